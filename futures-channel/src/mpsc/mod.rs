@@ -79,7 +79,7 @@
 // by the queue structure.
 
 use futures_core::stream::{FusedStream, Stream};
-use futures_core::task::{Context, Poll, Waker};
+use futures_core::task::{poll_budget, Context, Poll, Waker};
 use futures_core::task::__internal::AtomicWaker;
 use std::any::Any;
 use std::error::Error;
@@ -906,6 +906,11 @@ impl<T> Stream for Receiver<T> {
         mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<Option<T>> {
+        // Yield to the executor if this task has used up its budget.
+        if poll_budget(cx).is_pending() {
+            return Poll::Pending;
+        }
+
             // Try to read a message off of the message queue.
         match self.next_message() {
             Poll::Ready(msg) => {
@@ -0,0 +1,61 @@
+use core::cell::Cell;
+use core::task::{Context, Poll};
+
+/// The budget executors of the `futures` crates give each poll of a task.
+pub const DEFAULT_BUDGET: usize = 128;
+
+thread_local! {
+    // `None` when no budget is being enforced on this thread.
+    static BUDGET: Cell<Option<usize>> = Cell::new(None);
+}
+
+/// Runs `f` with a cooperative scheduling budget of `budget` units.
+///
+/// Executors call this around each poll of a task. While `f` runs, every
+/// call to [`poll_budget`] on this thread consumes one unit, and once the
+/// budget is exhausted, leaf futures and streams yield back to the executor
+/// instead of making further progress. This keeps a task which is always
+/// ready, such as one draining a fast channel, from starving the other tasks
+/// of its executor.
+///
+/// Budgets don't nest: an inner call replaces the budget for the duration
+/// of `f`, and the outer budget is restored afterwards.
+pub fn with_budget<R, F: FnOnce() -> R>(budget: usize, f: F) -> R {
+    struct Reset(Option<usize>);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            let prev = self.0;
+            BUDGET.with(|cell| cell.set(prev));
+        }
+    }
+
+    let _reset = Reset(BUDGET.with(|cell| cell.replace(Some(budget))));
+    f()
+}
+
+/// Consumes one unit of the current task's cooperative scheduling budget.
+///
+/// Returns `Poll::Ready(())` if the caller may make progress. Once the budget
+/// set by [`with_budget`] is exhausted, returns `Poll::Pending` after waking
+/// the current task, so that it is polled again once the executor has given
+/// its other tasks a turn. Outside of [`with_budget`], this always returns
+/// `Poll::Ready(())`.
+///
+/// Leaf futures and streams should call this before doing work that could
+/// complete immediately, and return `Poll::Pending` if it does.
+pub fn poll_budget(cx: &mut Context<'_>) -> Poll<()> {
+    BUDGET.with(|cell| {
+        match cell.get() {
+            None => Poll::Ready(()),
+            Some(0) => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            Some(n) => {
+                cell.set(Some(n - 1));
+                Poll::Ready(())
+            }
+        }
+    })
+}
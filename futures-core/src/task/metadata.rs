@@ -0,0 +1,99 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt;
+
+/// Information describing a task, passed to executors along with the task's
+/// future through
+/// [`Spawn::spawn_obj_with_metadata`](crate::task::Spawn::spawn_obj_with_metadata).
+///
+/// A task can be given a name and the location it was spawned from, which
+/// executors may use to identify it in debug output or instrumentation, and
+/// any number of additional values which are looked up by their type.
+#[derive(Default)]
+pub struct TaskMetadata {
+    name: Option<Cow<'static, str>>,
+    location: Option<SpawnLocation>,
+    extensions: Vec<Box<dyn Any + Send + Sync>>,
+}
+
+impl TaskMetadata {
+    /// Creates empty metadata, with no name and no additional values.
+    pub fn new() -> TaskMetadata {
+        TaskMetadata::default()
+    }
+
+    /// Returns the name of the task, if it was given one.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(|name| name.as_ref())
+    }
+
+    /// Sets the name of the task.
+    pub fn set_name<N: Into<Cow<'static, str>>>(&mut self, name: N) {
+        self.name = Some(name.into());
+    }
+
+    /// Returns the location the task was spawned from, if it was recorded.
+    pub fn location(&self) -> Option<SpawnLocation> {
+        self.location
+    }
+
+    /// Sets the location the task was spawned from.
+    pub fn set_location(&mut self, location: SpawnLocation) {
+        self.location = Some(location);
+    }
+
+    /// Inserts an additional value into the metadata, replacing any value of
+    /// the same type.
+    pub fn insert<T: Any + Send + Sync>(&mut self, value: T) {
+        self.extensions.retain(|existing| !existing.is::<T>());
+        self.extensions.push(Box::new(value));
+    }
+
+    /// Returns a reference to the additional value of type `T`, if any.
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.extensions.iter().filter_map(|value| value.downcast_ref()).next()
+    }
+}
+
+impl fmt::Debug for TaskMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskMetadata")
+            .field("name", &self.name)
+            .field("location", &self.location)
+            .field("extensions", &self.extensions.len())
+            .finish()
+    }
+}
+
+/// A source location a task was spawned from, usually created from the
+/// `file!()` and `line!()` macros.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpawnLocation {
+    file: &'static str,
+    line: u32,
+}
+
+impl SpawnLocation {
+    /// Creates a location pointing at `line` of `file`.
+    pub fn new(file: &'static str, line: u32) -> SpawnLocation {
+        SpawnLocation { file, line }
+    }
+
+    /// Returns the name of the source file.
+    pub fn file(&self) -> &'static str {
+        self.file
+    }
+
+    /// Returns the line number in the source file.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+}
+
+impl fmt::Display for SpawnLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
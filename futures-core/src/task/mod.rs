@@ -5,4 +5,14 @@ mod spawn;
 pub mod __internal;
 pub use self::spawn::{Spawn, LocalSpawn, SpawnError};
 
+#[cfg(feature = "std")]
+mod budget;
+#[cfg(feature = "std")]
+pub use self::budget::{poll_budget, with_budget, DEFAULT_BUDGET};
+
+#[cfg(feature = "alloc")]
+mod metadata;
+#[cfg(feature = "alloc")]
+pub use self::metadata::{SpawnLocation, TaskMetadata};
+
 pub use core::task::{Context, Poll, Waker, RawWaker, RawWakerVTable};
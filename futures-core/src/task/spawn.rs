@@ -1,4 +1,6 @@
 use crate::future::{FutureObj, LocalFutureObj};
+#[cfg(feature = "alloc")]
+use crate::task::TaskMetadata;
 use core::fmt;
 
 /// The `Spawn` trait allows for pushing futures onto an executor that will
@@ -15,6 +17,27 @@ pub trait Spawn {
     fn spawn_obj(&mut self, future: FutureObj<'static, ()>)
         -> Result<(), SpawnError>;
 
+    /// Spawns a future that will be run to completion, along with
+    /// metadata describing it.
+    ///
+    /// Executors which can make use of the metadata, for example to name
+    /// the task in debug output, should override this method. The default
+    /// implementation discards the metadata and calls
+    /// [`spawn_obj`](Spawn::spawn_obj).
+    ///
+    /// # Errors
+    ///
+    /// See [`spawn_obj`](Spawn::spawn_obj).
+    #[cfg(feature = "alloc")]
+    fn spawn_obj_with_metadata(
+        &mut self,
+        future: FutureObj<'static, ()>,
+        metadata: TaskMetadata,
+    ) -> Result<(), SpawnError> {
+        drop(metadata);
+        self.spawn_obj(future)
+    }
+
     /// Determines whether the executor is able to spawn new tasks.
     ///
     /// This method will return `Ok` when the executor is *likely*
@@ -41,6 +64,27 @@ pub trait LocalSpawn {
     fn spawn_local_obj(&mut self, future: LocalFutureObj<'static, ()>)
         -> Result<(), SpawnError>;
 
+    /// Spawns a future that will be run to completion, along with
+    /// metadata describing it.
+    ///
+    /// Executors which can make use of the metadata, for example to name
+    /// the task in debug output, should override this method. The default
+    /// implementation discards the metadata and calls
+    /// [`spawn_local_obj`](LocalSpawn::spawn_local_obj).
+    ///
+    /// # Errors
+    ///
+    /// See [`spawn_local_obj`](LocalSpawn::spawn_local_obj).
+    #[cfg(feature = "alloc")]
+    fn spawn_local_obj_with_metadata(
+        &mut self,
+        future: LocalFutureObj<'static, ()>,
+        metadata: TaskMetadata,
+    ) -> Result<(), SpawnError> {
+        drop(metadata);
+        self.spawn_local_obj(future)
+    }
+
     /// Determines whether the executor is able to spawn new tasks.
     ///
     /// This method will return `Ok` when the executor is *likely*
@@ -93,6 +137,12 @@ impl<Sp: ?Sized + Spawn> Spawn for &mut Sp {
         Sp::spawn_obj(self, future)
     }
 
+    #[cfg(feature = "alloc")]
+    fn spawn_obj_with_metadata(&mut self, future: FutureObj<'static, ()>, metadata: TaskMetadata)
+    -> Result<(), SpawnError> {
+        Sp::spawn_obj_with_metadata(self, future, metadata)
+    }
+
     fn status(&self) -> Result<(), SpawnError> {
         Sp::status(self)
     }
@@ -104,6 +154,12 @@ impl<Sp: ?Sized + LocalSpawn> LocalSpawn for &mut Sp {
         Sp::spawn_local_obj(self, future)
     }
 
+    #[cfg(feature = "alloc")]
+    fn spawn_local_obj_with_metadata(&mut self, future: LocalFutureObj<'static, ()>, metadata: TaskMetadata)
+    -> Result<(), SpawnError> {
+        Sp::spawn_local_obj_with_metadata(self, future, metadata)
+    }
+
     fn status_local(&self) -> Result<(), SpawnError> {
         Sp::status_local(self)
     }
@@ -120,6 +176,11 @@ mod if_alloc {
             (**self).spawn_obj(future)
         }
 
+        fn spawn_obj_with_metadata(&mut self, future: FutureObj<'static, ()>, metadata: TaskMetadata)
+        -> Result<(), SpawnError> {
+            (**self).spawn_obj_with_metadata(future, metadata)
+        }
+
         fn status(&self) -> Result<(), SpawnError> {
             (**self).status()
         }
@@ -131,6 +192,11 @@ mod if_alloc {
             (**self).spawn_local_obj(future)
         }
 
+        fn spawn_local_obj_with_metadata(&mut self, future: LocalFutureObj<'static, ()>, metadata: TaskMetadata)
+        -> Result<(), SpawnError> {
+            (**self).spawn_local_obj_with_metadata(future, metadata)
+        }
+
         fn status_local(&self) -> Result<(), SpawnError> {
             (**self).status_local()
         }
@@ -0,0 +1,85 @@
+use futures_core::task::{Poll, TaskMetadata};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Hooks which executors call as tasks move through their lifecycle.
+///
+/// An implementation can be registered with
+/// [`ThreadPoolBuilder::instrument`](crate::ThreadPoolBuilder::instrument)
+/// or [`LocalPoolBuilder::instrument`](crate::LocalPoolBuilder::instrument),
+/// for example to record how long tasks are queued, how long each poll
+/// takes, or which tasks never complete. All methods have empty default
+/// implementations.
+///
+/// The hooks are called synchronously from the executor, so they should be
+/// cheap. `on_wake` may be called from any thread holding a waker for the
+/// task, and concurrently with the other hooks.
+pub trait Instrument: Send + Sync {
+    /// Called when a task is spawned onto the executor.
+    fn on_spawn(&self, task: &TaskInfo<'_>) {
+        let _ = task;
+    }
+
+    /// Called right before the executor polls a task.
+    fn on_poll_start(&self, task: &TaskInfo<'_>) {
+        let _ = task;
+    }
+
+    /// Called right after the executor polled a task, with the result of
+    /// the poll.
+    fn on_poll_end(&self, task: &TaskInfo<'_>, result: Poll<()>) {
+        let _ = (task, result);
+    }
+
+    /// Called when a task is woken.
+    fn on_wake(&self, task: &TaskInfo<'_>) {
+        let _ = task;
+    }
+
+    /// Called when a task has run to completion.
+    ///
+    /// Tasks which are dropped before completing, for example because their
+    /// executor was dropped, do not get this call.
+    fn on_complete(&self, task: &TaskInfo<'_>) {
+        let _ = task;
+    }
+}
+
+/// A unique identifier for a task spawned onto one of the executors of this
+/// crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TaskId(usize);
+
+impl TaskId {
+    pub(crate) fn next() -> TaskId {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        TaskId(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Information about a task, passed to the hooks of an [`Instrument`].
+#[derive(Debug, Clone, Copy)]
+pub struct TaskInfo<'a> {
+    id: TaskId,
+    metadata: &'a TaskMetadata,
+}
+
+impl<'a> TaskInfo<'a> {
+    pub(crate) fn new(id: TaskId, metadata: &'a TaskMetadata) -> TaskInfo<'a> {
+        TaskInfo { id, metadata }
+    }
+
+    /// Returns the identifier of the task.
+    pub fn id(&self) -> TaskId {
+        self.id
+    }
+
+    /// Returns the name the task was spawned with, if any.
+    pub fn name(&self) -> Option<&'a str> {
+        self.metadata.name()
+    }
+
+    /// Returns the metadata the task was spawned with.
+    pub fn metadata(&self) -> &'a TaskMetadata {
+        self.metadata
+    }
+}
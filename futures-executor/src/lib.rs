@@ -14,17 +14,34 @@
 
 #![doc(html_root_url = "https://rust-lang-nursery.github.io/futures-api-docs/0.3.0-alpha.16/futures_executor")]
 
+#[cfg(feature = "std")]
+mod instrument;
+#[cfg(feature = "std")]
+pub use crate::instrument::{Instrument, TaskId, TaskInfo};
+
 #[cfg(feature = "std")]
 mod local_pool;
 #[cfg(feature = "std")]
-pub use crate::local_pool::{block_on, block_on_stream, BlockingStream, LocalPool, LocalSpawner};
+pub use crate::local_pool::{block_on, block_on_stream, block_on_timeout, BlockingStream, Timeout};
+#[cfg(feature = "std")]
+pub use crate::local_pool::{LocalPool, LocalPoolBuilder, LocalSpawner, Priority};
 
 #[cfg(feature = "std")]
 mod unpark_mutex;
 #[cfg(feature = "std")]
 mod thread_pool;
 #[cfg(feature = "std")]
-pub use crate::thread_pool::{ThreadPool, ThreadPoolBuilder};
+pub use crate::thread_pool::{PanicPolicy, ThreadPool, ThreadPoolBuilder};
+
+#[cfg(feature = "std")]
+mod thread_per_core;
+#[cfg(feature = "std")]
+pub use crate::thread_per_core::{ThreadPerCore, ThreadPerCoreBuilder};
+
+#[cfg(feature = "std")]
+mod watchdog;
+#[cfg(feature = "std")]
+pub use crate::watchdog::{TaskSnapshot, TaskState, Watchdog};
 
 #[cfg(feature = "std")]
 mod enter;
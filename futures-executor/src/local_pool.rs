@@ -1,16 +1,23 @@
 use crate::enter;
+use crate::instrument::{Instrument, TaskId, TaskInfo};
+use crate::watchdog::{TaskEntry, Watchdog};
 use futures_core::future::{Future, FutureObj, LocalFutureObj};
 use futures_core::stream::{Stream};
-use futures_core::task::{Context, Poll, Spawn, LocalSpawn, SpawnError};
+use futures_core::task::{Context, Poll, Spawn, LocalSpawn, SpawnError, TaskMetadata, Waker};
+use futures_core::task::{poll_budget, with_budget, DEFAULT_BUDGET};
 use futures_util::task::{waker_ref, ArcWake};
 use futures_util::stream::FuturesUnordered;
 use futures_util::stream::StreamExt;
 use futures_util::pin_mut;
-use std::cell::{RefCell};
+use std::cell::{Cell, RefCell};
+use std::error::Error;
+use std::fmt;
 use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
 use std::rc::{Rc, Weak};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
 
 /// A single-threaded task pool for polling futures to completion.
 ///
@@ -23,12 +30,47 @@ use std::thread::{self, Thread};
 /// [`spawner()`](LocalPool::spawner) method. Because the executor is
 /// single-threaded, it supports a special form of task spawning for non-`Send`
 /// futures, via [`spawn_local_obj`](futures_core::task::LocalSpawn::spawn_local_obj).
-#[derive(Debug)]
+///
+/// Tasks can be given a [`Priority`](Priority), in which case ready tasks of
+/// a higher priority are run before those of a lower priority.
 pub struct LocalPool {
-    pool: FuturesUnordered<LocalFutureObj<'static, ()>>,
+    // One set of tasks per priority, from highest to lowest.
+    pools: [FuturesUnordered<LocalTask>; 3],
+    // How many polls in a row each set was passed over for a higher one.
+    skipped: [usize; 3],
+    starvation_threshold: usize,
     incoming: Rc<Incoming>,
 }
 
+/// The priority of a task spawned onto a [`LocalPool`](LocalPool).
+///
+/// Whenever tasks of several priorities are ready to run, the pool runs
+/// those of the highest priority first. A priority is given to a task with
+/// [`LocalSpawner::spawn_with_priority`](LocalSpawner::spawn_with_priority),
+/// or by adding it to the task's
+/// [`TaskMetadata`](futures_core::task::TaskMetadata). Tasks without a
+/// priority run at `Priority::Normal`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// For background work, which runs when no other task is ready.
+    Low,
+    /// The priority of tasks which weren't given one.
+    Normal,
+    /// For latency-sensitive work, which runs ahead of all other tasks.
+    High,
+}
+
+impl Priority {
+    // The index of the task set for this priority.
+    fn index(self) -> usize {
+        match self {
+            Priority::High => 0,
+            Priority::Normal => 1,
+            Priority::Low => 2,
+        }
+    }
+}
+
 /// A handle to a [`LocalPool`](LocalPool) that implements
 /// [`Spawn`](futures_core::task::Spawn).
 #[derive(Clone, Debug)]
@@ -36,7 +78,171 @@ pub struct LocalSpawner {
     incoming: Weak<Incoming>,
 }
 
-type Incoming = RefCell<Vec<LocalFutureObj<'static, ()>>>;
+/// A builder for configuring and creating a [`LocalPool`](LocalPool).
+pub struct LocalPoolBuilder {
+    instrument: Option<Arc<dyn Instrument>>,
+    watchdog: Option<Watchdog>,
+    starvation_threshold: usize,
+}
+
+impl fmt::Debug for LocalPoolBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalPoolBuilder")
+            .field("instrument", &self.instrument.is_some())
+            .field("watchdog", &self.watchdog)
+            .field("starvation_threshold", &self.starvation_threshold)
+            .finish()
+    }
+}
+
+// Tasks spawned through a `LocalSpawner` which have not been added to the
+// pool yet.
+struct Incoming {
+    tasks: RefCell<Vec<LocalTask>>,
+    // Set by every task of the pool when it is polled.
+    polled: Rc<Cell<bool>>,
+    instrument: Option<Arc<dyn Instrument>>,
+    watchdog: Option<Watchdog>,
+}
+
+impl fmt::Debug for Incoming {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Incoming")
+            .field("tasks", &self.tasks)
+            .finish()
+    }
+}
+
+/// A task spawned onto a `LocalPool`, along with its metadata.
+struct LocalTask {
+    future: LocalFutureObj<'static, ()>,
+    id: TaskId,
+    metadata: Arc<TaskMetadata>,
+    // Only set when the pool is instrumented or has a watchdog.
+    waker: Option<Arc<TaskWaker>>,
+    polled: Rc<Cell<bool>>,
+}
+
+// Waker handed to instrumented or watched tasks, which reports wake-ups
+// before waking the pool.
+struct TaskWaker {
+    instrument: Option<Arc<dyn Instrument>>,
+    entry: Option<Arc<TaskEntry>>,
+    id: TaskId,
+    metadata: Arc<TaskMetadata>,
+    inner: Mutex<Option<Waker>>,
+}
+
+impl TaskWaker {
+    fn info(&self) -> TaskInfo<'_> {
+        TaskInfo::new(self.id, &self.metadata)
+    }
+}
+
+impl ArcWake for TaskWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        if let Some(instrument) = &arc_self.instrument {
+            instrument.on_wake(&arc_self.info());
+        }
+        if let Some(entry) = &arc_self.entry {
+            entry.wake();
+        }
+        if let Some(waker) = &*arc_self.inner.lock().unwrap() {
+            waker.wake_by_ref();
+        }
+    }
+}
+
+impl LocalTask {
+    fn new(
+        future: LocalFutureObj<'static, ()>,
+        metadata: TaskMetadata,
+        incoming: &Incoming,
+    ) -> LocalTask {
+        let id = TaskId::next();
+        let entry = incoming.watchdog.as_ref()
+            .map(|watchdog| watchdog.register(id, &metadata));
+        let metadata = Arc::new(metadata);
+        if let Some(instrument) = &incoming.instrument {
+            instrument.on_spawn(&TaskInfo::new(id, &metadata));
+        }
+        let waker = if incoming.instrument.is_some() || entry.is_some() {
+            Some(Arc::new(TaskWaker {
+                instrument: incoming.instrument.clone(),
+                entry,
+                id,
+                metadata: metadata.clone(),
+                inner: Mutex::new(None),
+            }))
+        } else {
+            None
+        };
+        let polled = incoming.polled.clone();
+        LocalTask { future, id, metadata, waker, polled }
+    }
+}
+
+impl Future for LocalTask {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = &mut *self;
+        this.polled.set(true);
+        let task_waker = match &this.waker {
+            Some(task_waker) => task_waker,
+            None => return with_budget(DEFAULT_BUDGET, || Pin::new(&mut this.future).poll(cx)),
+        };
+
+        {
+            let mut inner = task_waker.inner.lock().unwrap();
+            match &*inner {
+                Some(waker) if waker.will_wake(cx.waker()) => {}
+                _ => *inner = Some(cx.waker().clone()),
+            }
+        }
+
+        let info = task_waker.info();
+        if let Some(instrument) = &task_waker.instrument {
+            instrument.on_poll_start(&info);
+        }
+        if let Some(entry) = &task_waker.entry {
+            entry.poll_start();
+        }
+        let waker = waker_ref(task_waker);
+        let future = &mut this.future;
+        let res = with_budget(DEFAULT_BUDGET, || {
+            Pin::new(future).poll(&mut Context::from_waker(&waker))
+        });
+        if let Some(entry) = &task_waker.entry {
+            entry.poll_end(res);
+        }
+        if let Some(instrument) = &task_waker.instrument {
+            instrument.on_poll_end(&info, res);
+            if res.is_ready() {
+                instrument.on_complete(&info);
+            }
+        }
+        res
+    }
+}
+
+impl Drop for LocalTask {
+    fn drop(&mut self) {
+        // Tasks which are dropped before completing are no longer live.
+        if let Some(entry) = self.waker.as_ref().and_then(|waker| waker.entry.as_ref()) {
+            entry.finish();
+        }
+    }
+}
+
+impl fmt::Debug for LocalTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalTask")
+            .field("id", &self.id)
+            .field("name", &self.metadata.name())
+            .finish()
+    }
+}
 
 pub(crate) struct ThreadNotify {
     thread: Thread
@@ -73,6 +279,31 @@ fn run_executor<T, F: FnMut(&mut Context<'_>) -> Poll<T>>(mut f: F) -> T {
     })
 }
 
+// Like `run_executor`, but gives up once `deadline` has passed.
+fn run_executor_until<T, F: FnMut(&mut Context<'_>) -> Poll<T>>(
+    deadline: Instant,
+    mut f: F,
+) -> Option<T> {
+    let _enter = enter()
+        .expect("cannot execute `LocalPool` executor from within \
+                 another executor");
+
+    CURRENT_THREAD_NOTIFY.with(|thread_notify| {
+        let waker = waker_ref(thread_notify);
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(t) = f(&mut cx) {
+                return Some(t);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+            thread::park_timeout(deadline - now);
+        }
+    })
+}
+
 fn poll_executor<T, F: FnMut(&mut Context<'_>) -> T>(mut f: F) -> T {
     let _enter = enter()
         .expect("cannot execute `LocalPool` executor from within \
@@ -88,10 +319,16 @@ fn poll_executor<T, F: FnMut(&mut Context<'_>) -> T>(mut f: F) -> T {
 impl LocalPool {
     /// Create a new, empty pool of tasks.
     pub fn new() -> LocalPool {
-        LocalPool {
-            pool: FuturesUnordered::new(),
-            incoming: Default::default(),
-        }
+        LocalPoolBuilder::new().create()
+    }
+
+    /// Create a default pool configuration, which can then be customized.
+    ///
+    /// See documentation for the methods in
+    /// [`LocalPoolBuilder`](LocalPoolBuilder) for details on the default
+    /// configuration.
+    pub fn builder() -> LocalPoolBuilder {
+        LocalPoolBuilder::new()
     }
 
     /// Get a clonable handle to the pool as a [`Spawn`].
@@ -248,10 +485,12 @@ impl LocalPool {
     fn poll_pool(&mut self, cx: &mut Context<'_>) -> Poll<()> {
         // state for the FuturesUnordered, which will never be used
         loop {
-            let ret = self.poll_pool_once(cx);
+            // Give the pool itself a budget, so that tasks which keep waking
+            // themselves don't keep `run_until` from checking its future.
+            let ret = with_budget(DEFAULT_BUDGET, || self.poll_pool_once(cx));
 
             // we queued up some new tasks; add them and poll again
-            if !self.incoming.borrow().is_empty() {
+            if !self.incoming.tasks.borrow().is_empty() {
                 continue;
             }
 
@@ -268,14 +507,74 @@ impl LocalPool {
     fn poll_pool_once(&mut self, cx: &mut Context<'_>) -> Poll<Option<()>> {
         // empty the incoming queue of newly-spawned tasks
         {
-            let mut incoming = self.incoming.borrow_mut();
+            let mut incoming = self.incoming.tasks.borrow_mut();
             for task in incoming.drain(..) {
-                self.pool.push(task)
+                let priority = task.metadata.get::<Priority>().cloned()
+                    .unwrap_or(Priority::Normal);
+                self.pools[priority.index()].push(task)
+            }
+        }
+
+        'poll: loop {
+            // Go through the priorities from highest to lowest, except that
+            // one which has been passed over too many times in a row goes
+            // first.
+            let mut order = [0, 1, 2];
+            let threshold = self.starvation_threshold;
+            if let Some(starved) = self.skipped.iter().position(|&skipped| skipped >= threshold) {
+                order[..=starved].rotate_right(1);
+            }
+
+            // Poll a single ready task, of the first set which has one.
+            let mut pending = false;
+            for (i, &idx) in order.iter().enumerate() {
+                self.skipped[idx] = 0;
+                self.incoming.polled.set(false);
+                // A budget of one lets the set poll at most one task.
+                let pools = &mut self.pools;
+                let ret = with_budget(1, || pools[idx].poll_next_unpin(cx));
+                let polled = match ret {
+                    Poll::Ready(Some(())) => true,
+                    Poll::Ready(None) => false,
+                    Poll::Pending => {
+                        pending = true;
+                        self.incoming.polled.get()
+                    }
+                };
+                if !polled {
+                    continue;
+                }
+                for &later in &order[i + 1..] {
+                    if !self.pools[later].is_empty() {
+                        self.skipped[later] += 1;
+                    }
+                }
+                if ret.is_ready() {
+                    return Poll::Ready(Some(()));
+                }
+                // The task may have woken tasks of a higher priority, so
+                // start over from the top, unless the pool has used up its
+                // own budget.
+                if poll_budget(cx).is_pending() {
+                    return Poll::Pending;
+                }
+                continue 'poll;
             }
+            return if pending {
+                Poll::Pending
+            } else {
+                Poll::Ready(None)
+            };
         }
+    }
+}
 
-        // try to execute the next ready future
-        self.pool.poll_next_unpin(cx)
+impl fmt::Debug for LocalPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalPool")
+            .field("tasks", &self.pools.iter().flat_map(|pool| pool.iter()).collect::<Vec<_>>())
+            .field("incoming", &self.incoming)
+            .finish()
     }
 }
 
@@ -285,6 +584,70 @@ impl Default for LocalPool {
     }
 }
 
+impl LocalPoolBuilder {
+    /// Create a default pool configuration.
+    ///
+    /// See the other methods on this type for details on the defaults.
+    pub fn new() -> LocalPoolBuilder {
+        LocalPoolBuilder {
+            instrument: None,
+            watchdog: None,
+            starvation_threshold: 32,
+        }
+    }
+
+    /// Register an [`Instrument`](crate::Instrument) whose hooks are called
+    /// as tasks spawned onto the pool are polled, woken and completed.
+    ///
+    /// By default, no instrumentation is installed.
+    pub fn instrument<I>(&mut self, instrument: I) -> &mut Self
+        where I: Instrument + 'static
+    {
+        self.instrument = Some(Arc::new(instrument));
+        self
+    }
+
+    /// Attach a [`Watchdog`](crate::Watchdog) which tracks the tasks spawned
+    /// onto the pool.
+    ///
+    /// By default, no watchdog is attached.
+    pub fn watchdog(&mut self, watchdog: Watchdog) -> &mut Self {
+        self.watchdog = Some(watchdog);
+        self
+    }
+
+    /// Set after how many polls of higher-priority tasks ready tasks of a
+    /// lower priority get polled anyway.
+    ///
+    /// This keeps a steady stream of high priority tasks from starving lower
+    /// priority ones. By default, this is 32.
+    pub fn starvation_threshold(&mut self, threshold: usize) -> &mut Self {
+        self.starvation_threshold = threshold;
+        self
+    }
+
+    /// Create a [`LocalPool`](LocalPool) with the given configuration.
+    pub fn create(&mut self) -> LocalPool {
+        LocalPool {
+            pools: [FuturesUnordered::new(), FuturesUnordered::new(), FuturesUnordered::new()],
+            skipped: [0; 3],
+            starvation_threshold: self.starvation_threshold,
+            incoming: Rc::new(Incoming {
+                tasks: RefCell::new(Vec::new()),
+                polled: Rc::new(Cell::new(false)),
+                instrument: self.instrument.clone(),
+                watchdog: self.watchdog.clone(),
+            }),
+        }
+    }
+}
+
+impl Default for LocalPoolBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Run a future to completion on the current thread.
 ///
 /// This function will block the caller until the given future has completed.
@@ -296,6 +659,47 @@ pub fn block_on<F: Future>(f: F) -> F::Output {
     run_executor(|cx| f.as_mut().poll(cx))
 }
 
+/// Run a future to completion on the current thread, giving up after
+/// `timeout` has elapsed.
+///
+/// This function will block the caller until the given future has completed,
+/// or until the timeout has elapsed, in which case the future is dropped and
+/// [`Timeout`](Timeout) is returned.
+///
+/// ```
+/// use futures::executor::block_on_timeout;
+/// use futures::future::{pending, ready};
+/// use std::time::Duration;
+///
+/// assert_eq!(block_on_timeout(ready(1), Duration::from_secs(1)), Ok(1));
+/// assert!(block_on_timeout(pending::<()>(), Duration::from_millis(10)).is_err());
+/// ```
+pub fn block_on_timeout<F: Future>(f: F, timeout: Duration) -> Result<F::Output, Timeout> {
+    // A deadline too far away to be represented is as good as none.
+    let deadline = match Instant::now().checked_add(timeout) {
+        Some(deadline) => deadline,
+        None => return Ok(block_on(f)),
+    };
+    pin_mut!(f);
+    run_executor_until(deadline, |cx| f.as_mut().poll(cx))
+        .ok_or(Timeout { _a: () })
+}
+
+/// The error returned when a blocking operation with a timeout, such as
+/// [`block_on_timeout`](block_on_timeout), didn't complete in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout {
+    _a: (),
+}
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "operation timed out")
+    }
+}
+
+impl Error for Timeout {}
+
 /// Turn a stream into a blocking iterator.
 ///
 /// When `next` is called on the resulting `BlockingStream`, the caller
@@ -326,6 +730,22 @@ impl<S: Stream + Unpin> BlockingStream<S> {
     pub fn into_inner(self) -> S {
         self.stream
     }
+
+    /// Blocks until the next element of the stream becomes available, or
+    /// until `timeout` has elapsed.
+    ///
+    /// Returns `Ok(None)` if the stream has ended, and
+    /// [`Timeout`](Timeout) if no element became available in time. The
+    /// stream can still be used after a timeout.
+    pub fn next_timeout(&mut self, timeout: Duration) -> Result<Option<S::Item>, Timeout> {
+        let deadline = match Instant::now().checked_add(timeout) {
+            Some(deadline) => deadline,
+            None => return Ok(Iterator::next(self)),
+        };
+        let stream = &mut self.stream;
+        run_executor_until(deadline, |cx| stream.poll_next_unpin(cx))
+            .ok_or(Timeout { _a: () })
+    }
 }
 
 impl<S: Stream + Unpin> Iterator for BlockingStream<S> {
@@ -335,17 +755,54 @@ impl<S: Stream + Unpin> Iterator for BlockingStream<S> {
     }
 }
 
+impl LocalSpawner {
+    /// Spawns a task with the given priority onto the pool.
+    ///
+    /// ```
+    /// use futures::executor::{LocalPool, Priority};
+    /// use futures::future::lazy;
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let mut spawner = pool.spawner();
+    /// let order = Rc::new(RefCell::new(Vec::new()));
+    ///
+    /// let o = order.clone();
+    /// spawner.spawn_with_priority(lazy(move |_| o.borrow_mut().push("low")), Priority::Low).unwrap();
+    /// let o = order.clone();
+    /// spawner.spawn_with_priority(lazy(move |_| o.borrow_mut().push("high")), Priority::High).unwrap();
+    ///
+    /// pool.run();
+    /// assert_eq!(*order.borrow(), ["high", "low"]);
+    /// ```
+    pub fn spawn_with_priority<Fut>(
+        &mut self,
+        future: Fut,
+        priority: Priority,
+    ) -> Result<(), SpawnError>
+        where Fut: Future<Output = ()> + 'static
+    {
+        let mut metadata = TaskMetadata::new();
+        metadata.insert(priority);
+        self.spawn_local_obj_with_metadata(LocalFutureObj::new(Box::new(future)), metadata)
+    }
+}
+
 impl Spawn for LocalSpawner {
     fn spawn_obj(
         &mut self,
         future: FutureObj<'static, ()>,
     ) -> Result<(), SpawnError> {
-        if let Some(incoming) = self.incoming.upgrade() {
-            incoming.borrow_mut().push(future.into());
-            Ok(())
-        } else {
-            Err(SpawnError::shutdown())
-        }
+        self.spawn_local_obj_with_metadata(future.into(), TaskMetadata::new())
+    }
+
+    fn spawn_obj_with_metadata(
+        &mut self,
+        future: FutureObj<'static, ()>,
+        metadata: TaskMetadata,
+    ) -> Result<(), SpawnError> {
+        self.spawn_local_obj_with_metadata(future.into(), metadata)
     }
 
     fn status(&self) -> Result<(), SpawnError> {
@@ -361,9 +818,18 @@ impl LocalSpawn for LocalSpawner {
     fn spawn_local_obj(
         &mut self,
         future: LocalFutureObj<'static, ()>,
+    ) -> Result<(), SpawnError> {
+        self.spawn_local_obj_with_metadata(future, TaskMetadata::new())
+    }
+
+    fn spawn_local_obj_with_metadata(
+        &mut self,
+        future: LocalFutureObj<'static, ()>,
+        metadata: TaskMetadata,
     ) -> Result<(), SpawnError> {
         if let Some(incoming) = self.incoming.upgrade() {
-            incoming.borrow_mut().push(future);
+            let task = LocalTask::new(future, metadata, &incoming);
+            incoming.tasks.borrow_mut().push(task);
             Ok(())
         } else {
             Err(SpawnError::shutdown())
@@ -0,0 +1,206 @@
+use crate::local_pool::LocalPool;
+use futures_channel::mpsc;
+use futures_core::future::{Future, LocalFutureObj};
+use futures_core::task::{LocalSpawn, SpawnError};
+use futures_util::stream::StreamExt;
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+use std::thread;
+
+/// An executor which runs a [`LocalPool`](LocalPool) on each of a fixed set
+/// of threads, called shards.
+///
+/// Unlike [`ThreadPool`](crate::ThreadPool), tasks don't move between
+/// threads, so they don't need to be `Send`. Tasks are submitted to a shard
+/// as a `Send` closure which creates the task's future on the shard's own
+/// thread, through a lock-free inbox.
+///
+/// This type is a clonable handle to the shards. The shard threads exit once
+/// all handles have been dropped and they have drained their inboxes,
+/// dropping any tasks which haven't completed yet.
+///
+/// ```
+/// use futures::executor::ThreadPerCore;
+/// use futures::future::lazy;
+/// use std::rc::Rc;
+///
+/// let shards = ThreadPerCore::builder().shards(2).create().unwrap();
+///
+/// // `Rc` isn't `Send`, but the future never leaves the shard it is
+/// // created on.
+/// shards.spawn_on(1, || {
+///     let local = Rc::new(5);
+///     lazy(move |_| assert_eq!(*local, 5))
+/// }).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct ThreadPerCore {
+    inboxes: Arc<Vec<mpsc::UnboundedSender<Job>>>,
+}
+
+/// Configuration for a [`ThreadPerCore`](ThreadPerCore) executor.
+pub struct ThreadPerCoreBuilder {
+    shards: usize,
+    stack_size: usize,
+    name_prefix: Option<String>,
+}
+
+// Runs on the shard's thread and creates the future of a task.
+type Job = Box<dyn FnOnce() -> LocalFutureObj<'static, ()> + Send>;
+
+impl fmt::Debug for ThreadPerCore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadPerCore")
+            .field("shards", &self.inboxes.len())
+            .finish()
+    }
+}
+
+impl fmt::Debug for ThreadPerCoreBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadPerCoreBuilder")
+            .field("shards", &self.shards)
+            .field("name_prefix", &self.name_prefix)
+            .finish()
+    }
+}
+
+impl ThreadPerCore {
+    /// Creates a new executor with the default configuration.
+    ///
+    /// See documentation for the methods in
+    /// [`ThreadPerCoreBuilder`](ThreadPerCoreBuilder) for details on the
+    /// default configuration.
+    pub fn new() -> Result<ThreadPerCore, io::Error> {
+        ThreadPerCoreBuilder::new().create()
+    }
+
+    /// Create a default configuration, which can then be customized.
+    ///
+    /// See documentation for the methods in
+    /// [`ThreadPerCoreBuilder`](ThreadPerCoreBuilder) for details on the
+    /// default configuration.
+    pub fn builder() -> ThreadPerCoreBuilder {
+        ThreadPerCoreBuilder::new()
+    }
+
+    /// Returns the number of shards.
+    pub fn shards(&self) -> usize {
+        self.inboxes.len()
+    }
+
+    /// Spawns a task on the given shard.
+    ///
+    /// The closure `f` is sent to the shard's thread, where it is called to
+    /// create the task's future. The future itself is never sent to another
+    /// thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shard` is not less than [`shards`](ThreadPerCore::shards).
+    pub fn spawn_on<F, Fut>(&self, shard: usize, f: F) -> Result<(), SpawnError>
+        where F: FnOnce() -> Fut + Send + 'static,
+              Fut: Future<Output = ()> + 'static,
+    {
+        let job: Job = Box::new(move || LocalFutureObj::new(Box::new(f())));
+        self.inboxes[shard].unbounded_send(job)
+            .map_err(|_| SpawnError::shutdown())
+    }
+
+    /// Spawns a task on every shard.
+    ///
+    /// The closure `f` is shared by all shards, and called on each shard's
+    /// thread to create that shard's task.
+    pub fn broadcast<F, Fut>(&self, f: F) -> Result<(), SpawnError>
+        where F: Fn() -> Fut + Send + Sync + 'static,
+              Fut: Future<Output = ()> + 'static,
+    {
+        let f = Arc::new(f);
+        for shard in 0..self.shards() {
+            let f = f.clone();
+            self.spawn_on(shard, move || f())?;
+        }
+        Ok(())
+    }
+}
+
+impl ThreadPerCoreBuilder {
+    /// Create a default configuration.
+    ///
+    /// See the other methods on this type for details on the defaults.
+    pub fn new() -> ThreadPerCoreBuilder {
+        ThreadPerCoreBuilder {
+            shards: num_cpus::get(),
+            stack_size: 0,
+            name_prefix: None,
+        }
+    }
+
+    /// Set the number of shards, each of which runs on its own thread.
+    ///
+    /// By default, this is equal to the number of CPU cores.
+    pub fn shards(&mut self, shards: usize) -> &mut Self {
+        self.shards = shards;
+        self
+    }
+
+    /// Set stack size of the shard threads.
+    ///
+    /// By default, shard threads use Rust's standard stack size.
+    pub fn stack_size(&mut self, stack_size: usize) -> &mut Self {
+        self.stack_size = stack_size;
+        self
+    }
+
+    /// Set thread name prefix of the shard threads.
+    ///
+    /// Thread name prefix is used for generating thread names. For example,
+    /// if prefix is `my-shard-`, then the shard threads will get names like
+    /// `my-shard-1` etc, numbered by shard.
+    ///
+    /// By default, shard threads are assigned Rust's standard thread name.
+    pub fn name_prefix<S: Into<String>>(&mut self, name_prefix: S) -> &mut Self {
+        self.name_prefix = Some(name_prefix.into());
+        self
+    }
+
+    /// Create a [`ThreadPerCore`](ThreadPerCore) executor with the given
+    /// configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shards == 0`.
+    pub fn create(&mut self) -> Result<ThreadPerCore, io::Error> {
+        assert!(self.shards > 0);
+
+        let mut inboxes = Vec::with_capacity(self.shards);
+        for shard in 0..self.shards {
+            let (tx, rx) = mpsc::unbounded::<Job>();
+            let mut thread_builder = thread::Builder::new();
+            if let Some(ref name_prefix) = self.name_prefix {
+                thread_builder = thread_builder.name(format!("{}{}", name_prefix, shard));
+            }
+            if self.stack_size > 0 {
+                thread_builder = thread_builder.stack_size(self.stack_size);
+            }
+            thread_builder.spawn(move || {
+                let mut pool = LocalPool::new();
+                let mut spawner = pool.spawner();
+                pool.run_until(rx.for_each(move |job| {
+                    // Spawning only fails once the pool is gone.
+                    let _ = spawner.spawn_local_obj(job());
+                    futures_util::future::ready(())
+                }));
+            })?;
+            inboxes.push(tx);
+        }
+        Ok(ThreadPerCore { inboxes: Arc::new(inboxes) })
+    }
+}
+
+impl Default for ThreadPerCoreBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
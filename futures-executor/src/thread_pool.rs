@@ -1,21 +1,30 @@
 use crate::enter;
+use crate::instrument::{Instrument, TaskId, TaskInfo};
 use crate::unpark_mutex::UnparkMutex;
+use crate::watchdog::{TaskEntry, Watchdog};
 use futures_core::future::{Future, FutureObj};
-use futures_core::task::{Context, Poll, Spawn, SpawnError};
+use futures_core::task::{Context, Poll, Spawn, SpawnError, TaskMetadata};
+use futures_core::task::{with_budget, DEFAULT_BUDGET};
 use futures_util::future::FutureExt;
 use futures_util::task::{ArcWake, waker_ref};
+use std::any::Any;
 use std::io;
-use std::sync::{Arc, Mutex};
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::mpsc;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::thread;
+use std::time::{Duration, Instant};
 use std::fmt;
 
 /// A general-purpose thread pool for scheduling tasks that poll futures to
 /// completion.
 ///
-/// The thread pool multiplexes any number of tasks onto a fixed number of
-/// worker threads.
+/// The thread pool multiplexes any number of tasks onto a set of worker
+/// threads. The number of workers can be fixed, or vary between
+/// [`min_threads`](ThreadPoolBuilder::min_threads) and
+/// [`max_threads`](ThreadPoolBuilder::max_threads) with the load of the pool.
 ///
 /// This type is a clonable handle to the threadpool itself.
 /// Cloning it will only create a new reference, not a new threadpool.
@@ -25,27 +34,75 @@ pub struct ThreadPool {
 
 /// Thread pool configuration object.
 pub struct ThreadPoolBuilder {
-    pool_size: usize,
+    min_threads: Option<usize>,
+    max_threads: Option<usize>,
+    keep_alive: Duration,
     stack_size: usize,
     name_prefix: Option<String>,
     after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
     before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    instrument: Option<Arc<dyn Instrument>>,
+    watchdog: Option<Watchdog>,
+    panic_handler: Option<PanicHandler>,
+    panic_policy: PanicPolicy,
+}
+
+type PanicHandler = Arc<dyn Fn(Box<dyn Any + Send>) + Send + Sync>;
+
+/// What a [`ThreadPool`](ThreadPool) does after one of its tasks panicked.
+///
+/// In all cases, the panicking task is dropped, and the panic is first passed
+/// to the handler registered with
+/// [`ThreadPoolBuilder::panic_handler`](ThreadPoolBuilder::panic_handler),
+/// if any. If dropping the task panics too, that panic is handled in the same
+/// way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PanicPolicy {
+    /// Keep running the pool's other tasks on the same worker thread.
+    ///
+    /// This is the default.
+    Continue,
+    /// Abort the process.
+    Abort,
+    /// Shut down the pool: workers exit once the tasks queued before the
+    /// panic have been run, and spawning new tasks fails.
+    Shutdown,
 }
 
 trait AssertSendSync: Send + Sync {}
 impl AssertSendSync for ThreadPool {}
 
 struct PoolState {
-    tx: Mutex<mpsc::Sender<Message>>,
-    rx: Mutex<mpsc::Receiver<Message>>,
+    queue: Mutex<VecDeque<Message>>,
+    // Signalled whenever a message is queued.
+    message_queued: Condvar,
     cnt: AtomicUsize,
-    size: usize,
+    // Number of workers which have not been asked to exit yet.
+    threads: AtomicUsize,
+    // Number of workers waiting for a message.
+    idle: AtomicUsize,
+    // Number of tasks waiting for a worker.
+    queued: AtomicUsize,
+    min_threads: AtomicUsize,
+    max_threads: AtomicUsize,
+    keep_alive: Duration,
+    next_index: AtomicUsize,
+    stack_size: usize,
+    name_prefix: Option<String>,
+    // The hooks to run on every worker, including those started after the
+    // pool was created.
+    hooks: Hooks,
+    shut_down: AtomicBool,
+    instrument: Option<Arc<dyn Instrument>>,
+    watchdog: Option<Watchdog>,
+    panic_handler: Option<PanicHandler>,
+    panic_policy: PanicPolicy,
 }
 
 impl fmt::Debug for ThreadPool {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ThreadPool")
-            .field("size", &self.state.size)
+            .field("threads", &self.num_threads())
             .finish()
     }
 }
@@ -53,8 +110,11 @@ impl fmt::Debug for ThreadPool {
 impl fmt::Debug for ThreadPoolBuilder {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ThreadPoolBuilder")
-            .field("pool_size", &self.pool_size)
+            .field("min_threads", &self.min_threads)
+            .field("max_threads", &self.max_threads)
+            .field("keep_alive", &self.keep_alive)
             .field("name_prefix", &self.name_prefix)
+            .field("panic_policy", &self.panic_policy)
             .finish()
     }
 }
@@ -64,6 +124,11 @@ enum Message {
     Close,
 }
 
+struct Hooks {
+    after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+}
+
 impl ThreadPool {
     /// Creates a new thread pool with the default configuration.
     ///
@@ -95,6 +160,54 @@ impl ThreadPool {
     pub fn run<F: Future>(&mut self, f: F) -> F::Output {
         crate::LocalPool::new().run_until(f)
     }
+
+    /// Returns the current number of worker threads.
+    pub fn num_threads(&self) -> usize {
+        self.state.threads.load(Ordering::SeqCst)
+    }
+
+    /// Changes the number of worker threads to `size`.
+    ///
+    /// New workers are started right away. Surplus workers exit once they
+    /// have finished the tasks they are running and the tasks queued before
+    /// this call. If `size` is outside of the pool's
+    /// [`min_threads`](ThreadPoolBuilder::min_threads) and
+    /// [`max_threads`](ThreadPoolBuilder::max_threads) bounds, the bounds are
+    /// extended to include it, and the pool keeps adjusting its size within
+    /// them afterwards.
+    ///
+    /// Does nothing if the pool has been shut down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size == 0`.
+    pub fn resize(&self, size: usize) -> Result<(), io::Error> {
+        assert!(size > 0);
+        let state = &self.state;
+        if state.shut_down.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        if size < state.min_threads.load(Ordering::SeqCst) {
+            state.min_threads.store(size, Ordering::SeqCst);
+        }
+        if size > state.max_threads.load(Ordering::SeqCst) {
+            state.max_threads.store(size, Ordering::SeqCst);
+        }
+
+        let current = state.threads.swap(size, Ordering::SeqCst);
+        if size < current {
+            for _ in size..current {
+                state.send(Message::Close);
+            }
+        }
+        for _ in current..size {
+            if let Err(err) = spawn_worker(state) {
+                state.threads.fetch_sub(1, Ordering::SeqCst);
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Spawn for ThreadPool {
@@ -104,6 +217,18 @@ impl Spawn for ThreadPool {
     ) -> Result<(), SpawnError> {
         (&*self).spawn_obj(future)
     }
+
+    fn spawn_obj_with_metadata(
+        &mut self,
+        future: FutureObj<'static, ()>,
+        metadata: TaskMetadata,
+    ) -> Result<(), SpawnError> {
+        (&*self).spawn_obj_with_metadata(future, metadata)
+    }
+
+    fn status(&self) -> Result<(), SpawnError> {
+        self.state.status()
+    }
 }
 
 impl Spawn for &ThreadPool {
@@ -111,45 +236,185 @@ impl Spawn for &ThreadPool {
         &mut self,
         future: FutureObj<'static, ()>,
     ) -> Result<(), SpawnError> {
+        self.spawn_obj_with_metadata(future, TaskMetadata::new())
+    }
+
+    fn spawn_obj_with_metadata(
+        &mut self,
+        future: FutureObj<'static, ()>,
+        metadata: TaskMetadata,
+    ) -> Result<(), SpawnError> {
+        self.state.status()?;
+        let id = TaskId::next();
+        let entry = self.state.watchdog.as_ref()
+            .map(|watchdog| watchdog.register(id, &metadata));
         let task = Task {
             future,
             wake_handle: Arc::new(WakeHandle {
                 exec: self.clone(),
                 mutex: UnparkMutex::new(),
+                id,
+                metadata,
+                entry,
             }),
             exec: self.clone(),
         };
-        self.state.send(Message::Run(task));
+        if let Some(instrument) = &self.state.instrument {
+            instrument.on_spawn(&task.wake_handle.info());
+        }
+        schedule(&self.state, task);
         Ok(())
     }
+
+    fn status(&self) -> Result<(), SpawnError> {
+        self.state.status()
+    }
 }
 
 impl PoolState {
     fn send(&self, msg: Message) {
-        self.tx.lock().unwrap().send(msg).unwrap();
+        self.queue.lock().unwrap().push_back(msg);
+        self.message_queued.notify_one();
+    }
+
+    // Waits for the next message. Returns `None` once the worker has been
+    // idle for `keep_alive` and may exit.
+    //
+    // Workers wait on the condition variable rather than while holding the
+    // lock, so that all of them can time out at the same time.
+    fn next_message(&self) -> Option<Message> {
+        let mut queue = self.queue.lock().unwrap();
+        self.idle.fetch_add(1, Ordering::SeqCst);
+        let mut deadline = Instant::now().checked_add(self.keep_alive);
+        let msg = loop {
+            if let Some(msg) = queue.pop_front() {
+                break Some(msg);
+            }
+            let now = Instant::now();
+            match deadline {
+                Some(deadline_at) if now >= deadline_at => {
+                    if self.shut_down.load(Ordering::SeqCst) || self.retire() {
+                        break None;
+                    }
+                    deadline = now.checked_add(self.keep_alive);
+                }
+                _ => {}
+            }
+            queue = match deadline {
+                Some(deadline) => {
+                    self.message_queued.wait_timeout(queue, deadline - now).unwrap().0
+                }
+                None => self.message_queued.wait(queue).unwrap(),
+            };
+        };
+        self.idle.fetch_sub(1, Ordering::SeqCst);
+        msg
+    }
+
+    fn status(&self) -> Result<(), SpawnError> {
+        if self.shut_down.load(Ordering::SeqCst) {
+            Err(SpawnError::shutdown())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn shut_down(&self) {
+        if !self.shut_down.swap(true, Ordering::SeqCst) {
+            for _ in 0..self.threads.swap(0, Ordering::SeqCst) {
+                self.send(Message::Close);
+            }
+        }
+    }
+
+    // Lets an idle worker exit if the pool has more workers than it needs.
+    fn retire(&self) -> bool {
+        let mut threads = self.threads.load(Ordering::SeqCst);
+        loop {
+            if threads <= self.min_threads.load(Ordering::SeqCst) {
+                return false;
+            }
+            match self.threads.compare_exchange(threads, threads - 1, Ordering::SeqCst, Ordering::SeqCst) {
+                Ok(_) => return true,
+                Err(actual) => threads = actual,
+            }
+        }
+    }
+
+    fn handle_panic(&self, payload: Box<dyn Any + Send>) {
+        if let Some(panic_handler) = &self.panic_handler {
+            panic_handler(payload);
+        }
+        match self.panic_policy {
+            PanicPolicy::Continue => {}
+            PanicPolicy::Abort => process::abort(),
+            PanicPolicy::Shutdown => self.shut_down(),
+        }
     }
 
-    fn work(&self,
-            idx: usize,
-            after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
-            before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>) {
+    fn work(&self, idx: usize) {
         let _scope = enter().unwrap();
-        if let Some(after_start) = after_start {
+        if let Some(ref after_start) = self.hooks.after_start {
             after_start(idx);
         }
-        loop {
-            let msg = self.rx.lock().unwrap().recv().unwrap();
+        while let Some(msg) = self.next_message() {
             match msg {
-                Message::Run(task) => task.run(),
+                Message::Run(task) => {
+                    self.queued.fetch_sub(1, Ordering::SeqCst);
+                    task.run()
+                }
                 Message::Close => break,
             }
         }
-        if let Some(before_stop) = before_stop {
+        if let Some(ref before_stop) = self.hooks.before_stop {
             before_stop(idx);
         }
     }
 }
 
+// Starts a new worker thread. The caller must already have accounted for it
+// in `threads`.
+fn spawn_worker(state: &Arc<PoolState>) -> Result<(), io::Error> {
+    let idx = state.next_index.fetch_add(1, Ordering::SeqCst);
+    let mut thread_builder = thread::Builder::new();
+    if let Some(ref name_prefix) = state.name_prefix {
+        thread_builder = thread_builder.name(format!("{}{}", name_prefix, idx));
+    }
+    if state.stack_size > 0 {
+        thread_builder = thread_builder.stack_size(state.stack_size);
+    }
+    let worker_state = state.clone();
+    thread_builder.spawn(move || worker_state.work(idx))?;
+    Ok(())
+}
+
+// Queues a task to be run, starting a new worker if there are more queued
+// tasks than idle workers and the pool may still grow.
+fn schedule(state: &Arc<PoolState>, task: Task) {
+    let queued = state.queued.fetch_add(1, Ordering::SeqCst) + 1;
+    state.send(Message::Run(task));
+    if queued <= state.idle.load(Ordering::SeqCst) {
+        return;
+    }
+    let mut threads = state.threads.load(Ordering::SeqCst);
+    loop {
+        if state.shut_down.load(Ordering::SeqCst)
+            || threads >= state.max_threads.load(Ordering::SeqCst)
+        {
+            return;
+        }
+        match state.threads.compare_exchange(threads, threads + 1, Ordering::SeqCst, Ordering::SeqCst) {
+            Ok(_) => break,
+            Err(actual) => threads = actual,
+        }
+    }
+    // The task is already queued, so failing to start a worker only means
+    // that the pool doesn't grow this time.
+    if spawn_worker(state).is_err() {
+        state.threads.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 impl Clone for ThreadPool {
     fn clone(&self) -> ThreadPool {
         self.state.cnt.fetch_add(1, Ordering::Relaxed);
@@ -160,9 +425,7 @@ impl Clone for ThreadPool {
 impl Drop for ThreadPool {
     fn drop(&mut self) {
         if self.state.cnt.fetch_sub(1, Ordering::Relaxed) == 1 {
-            for _ in 0..self.state.size {
-                self.state.send(Message::Close);
-            }
+            self.state.shut_down();
         }
     }
 }
@@ -173,11 +436,17 @@ impl ThreadPoolBuilder {
     /// See the other methods on this type for details on the defaults.
     pub fn new() -> ThreadPoolBuilder {
         ThreadPoolBuilder {
-            pool_size: num_cpus::get(),
+            min_threads: None,
+            max_threads: None,
+            keep_alive: Duration::from_secs(10),
             stack_size: 0,
             name_prefix: None,
             after_start: None,
             before_stop: None,
+            instrument: None,
+            watchdog: None,
+            panic_handler: None,
+            panic_policy: PanicPolicy::Continue,
         }
     }
 
@@ -185,8 +454,44 @@ impl ThreadPoolBuilder {
     ///
     /// The size of a thread pool is the number of worker threads spawned.  By
     /// default, this is equal to the number of CPU cores.
+    ///
+    /// This is a shorthand for setting both
+    /// [`min_threads`](ThreadPoolBuilder::min_threads) and
+    /// [`max_threads`](ThreadPoolBuilder::max_threads) to `size`.
     pub fn pool_size(&mut self, size: usize) -> &mut Self {
-        self.pool_size = size;
+        self.min_threads = Some(size);
+        self.max_threads = Some(size);
+        self
+    }
+
+    /// Set the minimum number of worker threads of a future ThreadPool.
+    ///
+    /// The pool starts with this many workers, and never shrinks below it on
+    /// its own. By default, this is equal to the number of CPU cores, or to
+    /// [`max_threads`](ThreadPoolBuilder::max_threads) if that is lower.
+    pub fn min_threads(&mut self, min_threads: usize) -> &mut Self {
+        self.min_threads = Some(min_threads);
+        self
+    }
+
+    /// Set the maximum number of worker threads of a future ThreadPool.
+    ///
+    /// When a task is queued while all workers are busy, the pool starts a
+    /// new worker unless it already has this many. By default, this is equal
+    /// to the number of CPU cores, or to
+    /// [`min_threads`](ThreadPoolBuilder::min_threads) if that is higher.
+    pub fn max_threads(&mut self, max_threads: usize) -> &mut Self {
+        self.max_threads = Some(max_threads);
+        self
+    }
+
+    /// Set how long a worker thread may stay idle before it exits, as long
+    /// as the pool has more than
+    /// [`min_threads`](ThreadPoolBuilder::min_threads) workers.
+    ///
+    /// By default, this is 10 seconds.
+    pub fn keep_alive(&mut self, keep_alive: Duration) -> &mut Self {
+        self.keep_alive = keep_alive;
         self
     }
 
@@ -213,8 +518,11 @@ impl ThreadPoolBuilder {
     /// but before running any tasks on it.
     ///
     /// This hook is intended for bookkeeping and monitoring.
-    /// The closure `f` will be dropped after the `builder` is dropped
-    /// and all worker threads in the pool have executed it.
+    /// The closure `f` will be dropped after the `builder` and the pool are
+    /// dropped and all worker threads in the pool have exited.
+    ///
+    /// `f` is also run for every worker started as the pool grows or is
+    /// [resized](ThreadPool::resize).
     ///
     /// The closure provided will receive an index corresponding to the worker
     /// thread it's running on.
@@ -228,8 +536,12 @@ impl ThreadPoolBuilder {
     /// Execute closure `f` just prior to shutting down each worker thread.
     ///
     /// This hook is intended for bookkeeping and monitoring.
-    /// The closure `f` will be dropped after the `builder` is droppped
-    /// and all threads in the pool have executed it.
+    /// The closure `f` will be dropped after the `builder` and the pool are
+    /// droppped and all threads in the pool have executed it.
+    ///
+    /// `f` is also run for workers which exit as the pool shrinks, and for
+    /// every worker started as the pool grows or is
+    /// [resized](ThreadPool::resize).
     ///
     /// The closure provided will receive an index corresponding to the worker
     /// thread it's running on.
@@ -240,35 +552,96 @@ impl ThreadPoolBuilder {
         self
     }
 
+    /// Register an [`Instrument`](crate::Instrument) whose hooks are called
+    /// as tasks spawned onto the pool are polled, woken and completed.
+    ///
+    /// By default, no instrumentation is installed.
+    pub fn instrument<I>(&mut self, instrument: I) -> &mut Self
+        where I: Instrument + 'static
+    {
+        self.instrument = Some(Arc::new(instrument));
+        self
+    }
+
+    /// Attach a [`Watchdog`](crate::Watchdog) which tracks the tasks spawned
+    /// onto the pool.
+    ///
+    /// By default, no watchdog is attached.
+    pub fn watchdog(&mut self, watchdog: Watchdog) -> &mut Self {
+        self.watchdog = Some(watchdog);
+        self
+    }
+
+    /// Execute function `f` with the payload of every panic raised while
+    /// polling a task.
+    ///
+    /// The handler is called on the worker thread which caught the panic,
+    /// before the [`PanicPolicy`](PanicPolicy) is applied. Without a handler,
+    /// panics are only reported by the process's panic hook.
+    pub fn panic_handler<F>(&mut self, f: F) -> &mut Self
+        where F: Fn(Box<dyn Any + Send>) + Send + Sync + 'static
+    {
+        self.panic_handler = Some(Arc::new(f));
+        self
+    }
+
+    /// Set what the pool does after one of its tasks panicked.
+    ///
+    /// By default, the panicking task is dropped and the worker thread keeps
+    /// running the pool's other tasks.
+    pub fn panic_policy(&mut self, policy: PanicPolicy) -> &mut Self {
+        self.panic_policy = policy;
+        self
+    }
+
     /// Create a [`ThreadPool`](ThreadPool) with the given configuration.
     ///
     /// # Panics
     ///
-    /// Panics if `pool_size == 0`.
+    /// Panics if `max_threads == 0`, or if `min_threads` is greater than
+    /// `max_threads`.
     pub fn create(&mut self) -> Result<ThreadPool, io::Error> {
-        let (tx, rx) = mpsc::channel();
+        let (min_threads, max_threads) = match (self.min_threads, self.max_threads) {
+            (Some(min), Some(max)) => (min, max),
+            (Some(min), None) => (min, min.max(num_cpus::get())),
+            (None, Some(max)) => (max.min(num_cpus::get()), max),
+            (None, None) => (num_cpus::get(), num_cpus::get()),
+        };
+        assert!(max_threads > 0);
+        assert!(min_threads <= max_threads);
+
         let pool = ThreadPool {
             state: Arc::new(PoolState {
-                tx: Mutex::new(tx),
-                rx: Mutex::new(rx),
+                queue: Mutex::new(VecDeque::new()),
+                message_queued: Condvar::new(),
                 cnt: AtomicUsize::new(1),
-                size: self.pool_size,
+                threads: AtomicUsize::new(0),
+                idle: AtomicUsize::new(0),
+                queued: AtomicUsize::new(0),
+                min_threads: AtomicUsize::new(min_threads),
+                max_threads: AtomicUsize::new(max_threads),
+                keep_alive: self.keep_alive,
+                next_index: AtomicUsize::new(0),
+                stack_size: self.stack_size,
+                name_prefix: self.name_prefix.clone(),
+                hooks: Hooks {
+                    after_start: self.after_start.clone(),
+                    before_stop: self.before_stop.clone(),
+                },
+                shut_down: AtomicBool::new(false),
+                instrument: self.instrument.clone(),
+                watchdog: self.watchdog.clone(),
+                panic_handler: self.panic_handler.clone(),
+                panic_policy: self.panic_policy,
             }),
         };
-        assert!(self.pool_size > 0);
-
-        for counter in 0..self.pool_size {
-            let state = pool.state.clone();
-            let after_start = self.after_start.clone();
-            let before_stop = self.before_stop.clone();
-            let mut thread_builder = thread::Builder::new();
-            if let Some(ref name_prefix) = self.name_prefix {
-                thread_builder = thread_builder.name(format!("{}{}", name_prefix, counter));
-            }
-            if self.stack_size > 0 {
-                thread_builder = thread_builder.stack_size(self.stack_size);
+
+        for _ in 0..min_threads {
+            pool.state.threads.fetch_add(1, Ordering::SeqCst);
+            if let Err(err) = spawn_worker(&pool.state) {
+                pool.state.threads.fetch_sub(1, Ordering::SeqCst);
+                return Err(err);
             }
-            thread_builder.spawn(move || state.work(counter, after_start, before_stop))?;
         }
         Ok(pool)
     }
@@ -290,13 +663,22 @@ struct Task {
 struct WakeHandle {
     mutex: UnparkMutex<Task>,
     exec: ThreadPool,
+    id: TaskId,
+    metadata: TaskMetadata,
+    entry: Option<Arc<TaskEntry>>,
+}
+
+impl WakeHandle {
+    fn info(&self) -> TaskInfo<'_> {
+        TaskInfo::new(self.id, &self.metadata)
+    }
 }
 
 impl Task {
     /// Actually run the task (invoking `poll` on the future) on the current
     /// thread.
     fn run(self) {
-        let Task { mut future, wake_handle, mut exec } = self;
+        let Task { mut future, wake_handle, exec } = self;
         let waker = waker_ref(&wake_handle);
         let mut cx = Context::from_waker(&waker);
 
@@ -305,24 +687,56 @@ impl Task {
         unsafe {
             wake_handle.mutex.start_poll();
 
-            loop {
-                let res = future.poll_unpin(&mut cx);
-                match res {
-                    Poll::Pending => {}
-                    Poll::Ready(()) => return wake_handle.mutex.complete(),
-                }
-                let task = Task {
-                    future,
-                    wake_handle: wake_handle.clone(),
-                    exec,
-                };
-                match wake_handle.mutex.wait(task) {
-                    Ok(()) => return, // we've waited
-                    Err(task) => { // someone's notified us
-                        future = task.future;
-                        exec = task.exec;
+            let instrument = exec.state.instrument.as_ref();
+            if let Some(instrument) = instrument {
+                instrument.on_poll_start(&wake_handle.info());
+            }
+            if let Some(entry) = &wake_handle.entry {
+                entry.poll_start();
+            }
+            let poll = || with_budget(DEFAULT_BUDGET, || future.poll_unpin(&mut cx));
+            let res = match panic::catch_unwind(AssertUnwindSafe(poll)) {
+                Ok(res) => res,
+                Err(payload) => {
+                    if let Some(entry) = &wake_handle.entry {
+                        entry.finish();
                     }
+                    wake_handle.mutex.complete();
+                    // The future's destructor may panic as well, which must
+                    // not unwind through the worker either.
+                    let dropped = panic::catch_unwind(AssertUnwindSafe(|| drop(future)));
+                    exec.state.handle_panic(payload);
+                    if let Err(payload) = dropped {
+                        exec.state.handle_panic(payload);
+                    }
+                    return;
+                }
+            };
+            if let Some(entry) = &wake_handle.entry {
+                entry.poll_end(res);
+            }
+            if let Some(instrument) = instrument {
+                instrument.on_poll_end(&wake_handle.info(), res);
+            }
+            if let Poll::Ready(()) = res {
+                if let Some(instrument) = instrument {
+                    instrument.on_complete(&wake_handle.info());
                 }
+                return wake_handle.mutex.complete()
+            }
+
+            let state = exec.state.clone();
+            let task = Task {
+                future,
+                wake_handle: wake_handle.clone(),
+                exec,
+            };
+            match wake_handle.mutex.wait(task) {
+                Ok(()) => {} // we've waited
+                // Someone's notified us. Go to the back of the queue rather
+                // than polling again right away, so that tasks which keep
+                // waking themselves don't starve the others.
+                Err(task) => schedule(&state, task),
             }
         }
     }
@@ -331,6 +745,7 @@ impl Task {
 impl fmt::Debug for Task {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Task")
+            .field("name", &self.wake_handle.metadata.name())
             .field("contents", &"...")
             .finish()
     }
@@ -338,8 +753,14 @@ impl fmt::Debug for Task {
 
 impl ArcWake for WakeHandle {
     fn wake_by_ref(arc_self: &Arc<Self>) {
+        if let Some(instrument) = &arc_self.exec.state.instrument {
+            instrument.on_wake(&arc_self.info());
+        }
+        if let Some(entry) = &arc_self.entry {
+            entry.wake();
+        }
         match arc_self.mutex.notify() {
-            Ok(task) => arc_self.exec.state.send(Message::Run(task)),
+            Ok(task) => schedule(&arc_self.exec.state, task),
             Err(()) => {}
         }
     }
@@ -353,12 +774,14 @@ mod tests {
     #[test]
     fn test_drop_after_start() {
         let (tx, rx) = mpsc::sync_channel(2);
-        let _cpu_pool = ThreadPoolBuilder::new()
+        let cpu_pool = ThreadPoolBuilder::new()
             .pool_size(2)
             .after_start(move |_| tx.send(1).unwrap()).create().unwrap();
+        drop(cpu_pool);
 
-        // After ThreadPoolBuilder is deconstructed, the tx should be droped
-        // so that we can use rx as an iterator.
+        // After ThreadPoolBuilder is deconstructed and the workers have
+        // exited, the tx should be droped so that we can use rx as an
+        // iterator.
         let count = rx.into_iter().count();
         assert_eq!(count, 2);
     }
@@ -0,0 +1,324 @@
+use crate::instrument::TaskId;
+use futures_core::task::{Poll, SpawnLocation, TaskMetadata};
+use std::fmt;
+use std::io;
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Tracks the tasks of one or more executors to detect tasks which are never
+/// woken again, for example because of a lost wakeup.
+///
+/// A watchdog is attached to an executor with
+/// [`ThreadPoolBuilder::watchdog`](crate::ThreadPoolBuilder::watchdog) or
+/// [`LocalPoolBuilder::watchdog`](crate::LocalPoolBuilder::watchdog). It
+/// records when each task was last polled and last woken. A task which has
+/// been waiting for a wakeup for longer than the watchdog's threshold is
+/// considered stalled.
+///
+/// Stalled tasks can be queried with [`stalled`](Watchdog::stalled), or
+/// reported periodically from a background thread with
+/// [`spawn_monitor`](Watchdog::spawn_monitor). All live tasks can be dumped
+/// with [`tasks`](Watchdog::tasks). Tasks are identified in these reports by
+/// their name and spawn location, which can be set through the
+/// [task builder](futures_util::task::TaskBuilder).
+///
+/// ```
+/// use futures::executor::{LocalPool, Watchdog};
+/// use futures::future::pending;
+/// use futures::task::LocalSpawnExt;
+/// use std::time::Duration;
+///
+/// let watchdog = Watchdog::new(Duration::from_secs(0));
+/// let mut pool = LocalPool::builder().watchdog(watchdog.clone()).create();
+/// let mut spawner = pool.spawner();
+///
+/// spawner.build_local_task().name("lost").spawn_local(pending()).unwrap();
+/// pool.run_until_stalled();
+///
+/// let stalled = watchdog.stalled();
+/// assert_eq!(stalled[0].name(), Some("lost"));
+/// ```
+#[derive(Clone)]
+pub struct Watchdog {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    threshold: Duration,
+    tasks: Mutex<Vec<Weak<TaskEntry>>>,
+}
+
+impl fmt::Debug for Watchdog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Watchdog")
+            .field("threshold", &self.inner.threshold)
+            .finish()
+    }
+}
+
+impl Watchdog {
+    /// Creates a watchdog which considers tasks stalled once they have been
+    /// waiting for a wakeup for longer than `threshold`.
+    pub fn new(threshold: Duration) -> Watchdog {
+        Watchdog {
+            inner: Arc::new(Inner {
+                threshold,
+                tasks: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Returns the threshold after which waiting tasks are reported as
+    /// stalled.
+    pub fn threshold(&self) -> Duration {
+        self.inner.threshold
+    }
+
+    /// Returns a snapshot of all live tasks of the executors this watchdog is
+    /// attached to, in the order they were spawned.
+    pub fn tasks(&self) -> Vec<TaskSnapshot> {
+        self.inner.snapshot(|_| true)
+    }
+
+    /// Returns a snapshot of all tasks which have been waiting for a wakeup
+    /// for longer than the threshold.
+    pub fn stalled(&self) -> Vec<TaskSnapshot> {
+        let threshold = self.inner.threshold;
+        let now = Instant::now();
+        self.inner.snapshot(|task| task.is_stalled(threshold, now))
+    }
+
+    /// Spawns a background thread which checks for stalled tasks every
+    /// `interval`, and calls `report` with them whenever there are any.
+    ///
+    /// The thread exits once all clones of this watchdog, and all executors
+    /// it is attached to, have been dropped.
+    pub fn spawn_monitor<F>(&self, interval: Duration, mut report: F) -> Result<(), io::Error>
+        where F: FnMut(&[TaskSnapshot]) + Send + 'static
+    {
+        let inner = Arc::downgrade(&self.inner);
+        thread::Builder::new()
+            .name("futures-watchdog".to_string())
+            .spawn(move || {
+                loop {
+                    thread::sleep(interval);
+                    let watchdog = match inner.upgrade() {
+                        Some(inner) => Watchdog { inner },
+                        None => return,
+                    };
+                    let stalled = watchdog.stalled();
+                    if !stalled.is_empty() {
+                        report(&stalled);
+                    }
+                }
+            })?;
+        Ok(())
+    }
+
+    pub(crate) fn register(&self, id: TaskId, metadata: &TaskMetadata) -> Arc<TaskEntry> {
+        let entry = Arc::new(TaskEntry {
+            id,
+            name: metadata.name().map(String::from),
+            location: metadata.location(),
+            spawned: Instant::now(),
+            state: Mutex::new(EntryState {
+                state: Some(TaskState::Scheduled),
+                woken_while_running: false,
+                last_polled: None,
+                last_woken: None,
+            }),
+        });
+        let mut tasks = self.inner.tasks.lock().unwrap();
+        // Forget about tasks which have already finished so that the list
+        // doesn't grow without bounds.
+        tasks.retain(|task| match task.upgrade() {
+            Some(task) => !task.is_finished(),
+            None => false,
+        });
+        tasks.push(Arc::downgrade(&entry));
+        entry
+    }
+}
+
+impl Inner {
+    fn snapshot<F>(&self, mut filter: F) -> Vec<TaskSnapshot>
+        where F: FnMut(&TaskSnapshot) -> bool
+    {
+        let tasks = self.tasks.lock().unwrap();
+        tasks.iter()
+            .filter_map(|task| task.upgrade())
+            .filter_map(|task| task.snapshot())
+            .filter(|task| filter(task))
+            .collect()
+    }
+}
+
+/// The scheduling state of a task, as tracked by a [`Watchdog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskState {
+    /// The task has been spawned or woken, and is waiting to be polled.
+    Scheduled,
+    /// The task is being polled.
+    Running,
+    /// The task returned `Poll::Pending` and is waiting to be woken.
+    Idle,
+}
+
+/// A snapshot of a task tracked by a [`Watchdog`].
+#[derive(Debug, Clone)]
+pub struct TaskSnapshot {
+    id: TaskId,
+    name: Option<String>,
+    location: Option<SpawnLocation>,
+    state: TaskState,
+    spawned: Instant,
+    last_polled: Option<Instant>,
+    last_woken: Option<Instant>,
+}
+
+impl TaskSnapshot {
+    /// Returns the identifier of the task.
+    pub fn id(&self) -> TaskId {
+        self.id
+    }
+
+    /// Returns the name the task was spawned with, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(|name| &name[..])
+    }
+
+    /// Returns the location the task was spawned from, if it was recorded
+    /// with [`TaskBuilder::location`](futures_util::task::TaskBuilder::location).
+    pub fn location(&self) -> Option<SpawnLocation> {
+        self.location
+    }
+
+    /// Returns the scheduling state of the task.
+    pub fn state(&self) -> TaskState {
+        self.state
+    }
+
+    /// Returns the time at which the task was spawned.
+    pub fn spawned(&self) -> Instant {
+        self.spawned
+    }
+
+    /// Returns the time at which the task was last polled, if it has been
+    /// polled at all.
+    pub fn last_polled(&self) -> Option<Instant> {
+        self.last_polled
+    }
+
+    /// Returns the time at which the task was last woken, if it has been
+    /// woken at all.
+    pub fn last_woken(&self) -> Option<Instant> {
+        self.last_woken
+    }
+
+    fn is_stalled(&self, threshold: Duration, now: Instant) -> bool {
+        if self.state != TaskState::Idle {
+            return false;
+        }
+        let since = self.last_polled.unwrap_or(self.spawned);
+        now.duration_since(since) >= threshold
+    }
+}
+
+impl fmt::Display for TaskSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "task {:?} ({:?})", name, self.id)?,
+            None => write!(f, "unnamed task ({:?})", self.id)?,
+        }
+        if let Some(location) = self.location {
+            write!(f, " spawned at {}", location)?;
+        }
+        write!(f, " is {:?}", self.state)?;
+        if let Some(last_polled) = self.last_polled {
+            write!(f, ", last polled {:?} ago", last_polled.elapsed())?;
+        }
+        if let Some(last_woken) = self.last_woken {
+            write!(f, ", last woken {:?} ago", last_woken.elapsed())?;
+        }
+        Ok(())
+    }
+}
+
+// The state of a single task, shared between its executor and the watchdog.
+pub(crate) struct TaskEntry {
+    id: TaskId,
+    name: Option<String>,
+    location: Option<SpawnLocation>,
+    spawned: Instant,
+    state: Mutex<EntryState>,
+}
+
+struct EntryState {
+    // `None` once the task has finished.
+    state: Option<TaskState>,
+    woken_while_running: bool,
+    last_polled: Option<Instant>,
+    last_woken: Option<Instant>,
+}
+
+impl TaskEntry {
+    pub(crate) fn poll_start(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.state = Some(TaskState::Running);
+        state.woken_while_running = false;
+        state.last_polled = Some(Instant::now());
+    }
+
+    pub(crate) fn poll_end(&self, result: Poll<()>) {
+        let mut state = self.state.lock().unwrap();
+        state.state = match result {
+            Poll::Ready(()) => None,
+            Poll::Pending if state.woken_while_running => Some(TaskState::Scheduled),
+            Poll::Pending => Some(TaskState::Idle),
+        };
+        state.last_polled = Some(Instant::now());
+    }
+
+    pub(crate) fn wake(&self) {
+        let mut state = self.state.lock().unwrap();
+        match state.state {
+            Some(TaskState::Running) => state.woken_while_running = true,
+            Some(TaskState::Idle) => state.state = Some(TaskState::Scheduled),
+            Some(TaskState::Scheduled) | None => {}
+        }
+        state.last_woken = Some(Instant::now());
+    }
+
+    // Marks the task as finished, whether it completed or was dropped.
+    pub(crate) fn finish(&self) {
+        self.state.lock().unwrap().state = None;
+    }
+
+    fn is_finished(&self) -> bool {
+        self.state.lock().unwrap().state.is_none()
+    }
+
+    fn snapshot(&self) -> Option<TaskSnapshot> {
+        let state = self.state.lock().unwrap();
+        Some(TaskSnapshot {
+            id: self.id,
+            name: self.name.clone(),
+            location: self.location,
+            state: state.state?,
+            spawned: self.spawned,
+            last_polled: state.last_polled,
+            last_woken: state.last_woken,
+        })
+    }
+}
+
+impl fmt::Debug for TaskEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskEntry")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("location", &self.location)
+            .finish()
+    }
+}
@@ -0,0 +1,56 @@
+use futures::channel::{mpsc, oneshot};
+use futures::executor::{block_on_stream, block_on_timeout};
+use futures::future::{self, FutureExt};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[test]
+fn block_on_timeout_completes() {
+    let (tx, rx) = oneshot::channel();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        tx.send(5).unwrap();
+    });
+    assert_eq!(block_on_timeout(rx.map(Result::unwrap), Duration::from_secs(10)), Ok(5));
+}
+
+#[test]
+fn block_on_timeout_times_out() {
+    let start = Instant::now();
+    let err = block_on_timeout(future::pending::<()>(), Duration::from_millis(20)).unwrap_err();
+    assert!(start.elapsed() >= Duration::from_millis(20));
+    assert_eq!(err.to_string(), "operation timed out");
+}
+
+#[test]
+fn next_timeout() {
+    let (tx, rx) = mpsc::unbounded();
+    let mut stream = block_on_stream(rx);
+
+    assert!(stream.next_timeout(Duration::from_millis(10)).is_err());
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(stream.next_timeout(Duration::from_millis(10)), Ok(Some(1)));
+
+    let tx2 = tx.clone();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(10));
+        tx2.unbounded_send(2).unwrap();
+    });
+    assert_eq!(stream.next_timeout(Duration::from_secs(10)), Ok(Some(2)));
+
+    drop(tx);
+    assert_eq!(stream.next_timeout(Duration::from_secs(10)), Ok(None));
+}
+
+#[test]
+fn huge_timeouts_do_not_overflow() {
+    let forever = Duration::from_secs(u64::max_value());
+    assert_eq!(block_on_timeout(future::ready(1), forever), Ok(1));
+
+    let (tx, rx) = mpsc::unbounded();
+    let mut stream = block_on_stream(rx);
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(stream.next_timeout(forever), Ok(Some(1)));
+    drop(tx);
+    assert_eq!(stream.next_timeout(forever), Ok(None));
+}
@@ -0,0 +1,106 @@
+use futures::channel::oneshot;
+use futures::executor::{block_on, Instrument, LocalPool, TaskId, TaskInfo, ThreadPool};
+use futures::future::{self, FutureExt};
+use futures::task::{LocalSpawnExt, Poll, SpawnExt};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+#[derive(Clone, Default)]
+struct Record(Arc<Mutex<Vec<String>>>);
+
+impl Record {
+    fn push(&self, event: &str, task: &TaskInfo<'_>) {
+        let name = task.name().unwrap_or("?");
+        self.0.lock().unwrap().push(format!("{} {}", event, name));
+    }
+
+    fn events(&self) -> Vec<String> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+impl Instrument for Record {
+    fn on_spawn(&self, task: &TaskInfo<'_>) {
+        self.push("spawn", task);
+    }
+
+    fn on_poll_start(&self, task: &TaskInfo<'_>) {
+        self.push("poll", task);
+    }
+
+    fn on_poll_end(&self, task: &TaskInfo<'_>, result: Poll<()>) {
+        self.push(if result.is_ready() { "ready" } else { "pending" }, task);
+    }
+
+    fn on_wake(&self, task: &TaskInfo<'_>) {
+        self.push("wake", task);
+    }
+
+    fn on_complete(&self, task: &TaskInfo<'_>) {
+        self.push("complete", task);
+    }
+}
+
+#[test]
+fn local_pool_lifecycle() {
+    let record = Record::default();
+    let mut pool = LocalPool::builder().instrument(record.clone()).create();
+    let mut spawner = pool.spawner();
+
+    let (tx, rx) = oneshot::channel::<()>();
+    spawner.build_local_task().name("a").spawn_local(rx.map(|res| res.unwrap())).unwrap();
+    pool.run_until_stalled();
+    tx.send(()).unwrap();
+    pool.run_until_stalled();
+
+    assert_eq!(record.events(), vec![
+        "spawn a", "poll a", "pending a", "wake a", "poll a", "ready a", "complete a",
+    ]);
+}
+
+#[test]
+fn thread_pool_lifecycle() {
+    let record = Record::default();
+    let pool = ThreadPool::builder().pool_size(1).instrument(record.clone()).create().unwrap();
+
+    let (tx, rx) = oneshot::channel::<()>();
+    let handle = pool.clone().build_task().name("a").spawn_with_handle(rx.map(|res| res.unwrap())).unwrap();
+    tx.send(()).unwrap();
+    block_on(handle);
+
+    // The handle resolves while the task is being polled, so the remaining
+    // hooks may not have run yet.
+    let mut events = record.events();
+    for _ in 0..1000 {
+        if events.last().map(String::as_str) == Some("complete a") {
+            break;
+        }
+        thread::sleep(Duration::from_millis(1));
+        events = record.events();
+    }
+    assert_eq!(events.first().map(String::as_str), Some("spawn a"));
+    assert!(events.ends_with(&["ready a".to_string(), "complete a".to_string()]), "{:?}", events);
+    assert_eq!(events.iter().filter(|e| *e == "complete a").count(), 1);
+}
+
+#[test]
+fn task_ids_are_unique() {
+    struct Ids(Arc<Mutex<Vec<TaskId>>>);
+
+    impl Instrument for Ids {
+        fn on_spawn(&self, task: &TaskInfo<'_>) {
+            self.0.lock().unwrap().push(task.id());
+        }
+    }
+
+    let ids = Arc::new(Mutex::new(Vec::new()));
+    let pool = LocalPool::builder().instrument(Ids(ids.clone())).create();
+    let mut spawner = pool.spawner();
+    spawner.spawn_local(future::ready(())).unwrap();
+    spawner.spawn_local(future::ready(())).unwrap();
+
+    let ids = ids.lock().unwrap();
+    assert_eq!(ids.len(), 2);
+    assert_ne!(ids[0], ids[1]);
+}
@@ -311,3 +311,163 @@ fn tasks_are_scheduled_fairly() {
 
     pool.run();
 }
+
+#[test]
+fn debug_shows_task_names() {
+    use futures::task::LocalSpawnExt;
+
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    spawn.build_local_task().name("conn-handler").spawn_local(pending()).unwrap();
+    spawn.spawn_local(pending()).unwrap();
+
+    pool.run_until_stalled();
+    let debug = format!("{:?}", pool);
+    assert!(debug.contains("Some(\"conn-handler\")"), "{}", debug);
+    assert!(debug.contains("name: None"), "{}", debug);
+}
+
+#[test]
+fn busy_task_does_not_starve_others() {
+    use futures::channel::mpsc;
+    use futures::future::ready;
+    use futures::stream::StreamExt;
+    use futures::task::LocalSpawnExt;
+
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+
+    // A stream which is always ready, as it is fed by its own consumer.
+    let (tx, rx) = mpsc::unbounded();
+    tx.unbounded_send(()).unwrap();
+    spawn.spawn_local(rx.for_each(move |()| {
+        tx.unbounded_send(()).unwrap();
+        ready(())
+    })).unwrap();
+
+    let (done_tx, done_rx) = oneshot::channel();
+    spawn.spawn_local(lazy(move |_| done_tx.send(()).unwrap())).unwrap();
+    pool.run_until(done_rx).unwrap();
+}
+
+#[test]
+fn higher_priority_tasks_run_first() {
+    use futures::executor::Priority;
+    use futures::task::LocalSpawnExt;
+
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    let order = Rc::new(RefCell::new(Vec::new()));
+
+    for &(name, priority) in &[("low", Priority::Low), ("normal", Priority::Normal), ("high", Priority::High)] {
+        let order = order.clone();
+        spawn.spawn_with_priority(lazy(move |_| order.borrow_mut().push(name)), priority).unwrap();
+    }
+    let o = order.clone();
+    spawn.spawn_local(lazy(move |_| o.borrow_mut().push("default"))).unwrap();
+    let o = order.clone();
+    spawn.build_local_task()
+        .metadata(Priority::High)
+        .spawn_local(lazy(move |_| o.borrow_mut().push("metadata")))
+        .unwrap();
+
+    pool.run_until_stalled();
+    let order = order.borrow();
+    assert_eq!(order.len(), 5);
+    let mut high = order[..2].to_vec();
+    high.sort();
+    assert_eq!(high, ["high", "metadata"]);
+    let mut normal = order[2..4].to_vec();
+    normal.sort();
+    assert_eq!(normal, ["default", "normal"]);
+    assert_eq!(order[4], "low");
+}
+
+#[test]
+fn woken_higher_priority_task_runs_before_other_ready_tasks() {
+    use futures::executor::Priority;
+
+    let mut pool = LocalPool::new();
+    let mut spawn = pool.spawner();
+    let order = Rc::new(RefCell::new(Vec::new()));
+
+    let (tx, rx) = oneshot::channel::<()>();
+    let o = order.clone();
+    let mut rx = Some(rx);
+    spawn.spawn_with_priority(poll_fn(move |cx| {
+        match Pin::new(rx.as_mut().unwrap()).poll(cx) {
+            Poll::Ready(_) => {
+                o.borrow_mut().push("high");
+                Poll::Ready(())
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }), Priority::High).unwrap();
+    pool.run_until_stalled();
+
+    // The first low priority task wakes the high priority one, but doesn't
+    // complete itself.
+    let o = order.clone();
+    let mut tx = Some(tx);
+    spawn.spawn_with_priority(poll_fn(move |_| {
+        if let Some(tx) = tx.take() {
+            o.borrow_mut().push("low 1");
+            tx.send(()).unwrap();
+        }
+        Poll::Pending
+    }), Priority::Low).unwrap();
+    for &name in &["low 2", "low 3"] {
+        let o = order.clone();
+        spawn.spawn_with_priority(lazy(move |_| o.borrow_mut().push(name)), Priority::Low).unwrap();
+    }
+
+    pool.run_until_stalled();
+    assert_eq!(*order.borrow(), ["low 1", "high", "low 2", "low 3"]);
+}
+
+#[test]
+fn pending_polls_count_towards_starvation() {
+    use futures::executor::Priority;
+
+    let mut pool = LocalPool::builder().starvation_threshold(2).create();
+    let mut spawn = pool.spawner();
+    let order = Rc::new(RefCell::new(Vec::new()));
+
+    let o = order.clone();
+    spawn.spawn_with_priority(lazy(move |_| o.borrow_mut().push("low")), Priority::Low).unwrap();
+    // A high priority task which wakes itself and stays pending three times.
+    let o = order.clone();
+    let mut polls = 0;
+    spawn.spawn_with_priority(poll_fn(move |cx| {
+        o.borrow_mut().push("high");
+        polls += 1;
+        if polls == 4 {
+            Poll::Ready(())
+        } else {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }), Priority::High).unwrap();
+
+    pool.run_until_stalled();
+    assert_eq!(*order.borrow(), ["high", "high", "low", "high", "high"]);
+}
+
+#[test]
+fn starved_tasks_are_promoted() {
+    use futures::executor::Priority;
+
+    let mut pool = LocalPool::builder().starvation_threshold(2).create();
+    let mut spawn = pool.spawner();
+    let order = Rc::new(RefCell::new(Vec::new()));
+
+    let o = order.clone();
+    spawn.spawn_with_priority(lazy(move |_| o.borrow_mut().push("low")), Priority::Low).unwrap();
+    for _ in 0..5 {
+        let o = order.clone();
+        spawn.spawn_with_priority(lazy(move |_| o.borrow_mut().push("high")), Priority::High).unwrap();
+    }
+
+    while pool.try_run_one() {}
+    assert_eq!(*order.borrow(), ["high", "high", "low", "high", "high", "high"]);
+}
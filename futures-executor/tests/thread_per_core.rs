@@ -0,0 +1,61 @@
+use futures::channel::oneshot;
+use futures::executor::{block_on, ThreadPerCore};
+use futures::future::{lazy, poll_fn, FutureExt};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+#[test]
+fn spawn_on_runs_on_the_shard_thread() {
+    let shards = ThreadPerCore::builder().shards(2).name_prefix("shard-").create().unwrap();
+    assert_eq!(shards.shards(), 2);
+
+    let (tx, rx) = oneshot::channel();
+    shards.spawn_on(1, move || {
+        let local = Rc::new(Cell::new(0));
+        let local2 = local.clone();
+        lazy(move |_| local2.set(1)).then(move |()| {
+            local.set(local.get() + 1);
+            lazy(move |_| {
+                let name = thread::current().name().map(String::from);
+                tx.send((local.get(), name)).unwrap();
+            })
+        })
+    }).unwrap();
+
+    assert_eq!(block_on(rx).unwrap(), (2, Some("shard-1".to_string())));
+}
+
+#[test]
+fn broadcast_reaches_every_shard() {
+    let shards = ThreadPerCore::builder().shards(3).name_prefix("shard-").create().unwrap();
+
+    let (tx, rx) = mpsc::channel();
+    let tx = Mutex::new(tx);
+    shards.broadcast(move || {
+        let name = thread::current().name().map(String::from);
+        let tx = tx.lock().unwrap().clone();
+        lazy(move |_| tx.send(name).unwrap())
+    }).unwrap();
+
+    let mut names = (0..3).map(|_| rx.recv().unwrap().unwrap()).collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, vec!["shard-0", "shard-1", "shard-2"]);
+}
+
+#[test]
+fn shards_exit_when_dropped() {
+    let shards = ThreadPerCore::builder().shards(1).create().unwrap();
+    let (tx, rx) = oneshot::channel::<()>();
+    shards.spawn_on(0, move || rx.map(|_| ())).unwrap();
+    let clone = shards.clone();
+    drop(shards);
+    assert!(clone.spawn_on(0, || lazy(|_| ())).is_ok());
+    drop(clone);
+
+    // The pending task is dropped along with its shard, dropping the
+    // receiver.
+    let mut tx = tx;
+    block_on(poll_fn(|cx| tx.poll_cancel(cx)));
+}
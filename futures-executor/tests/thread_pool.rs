@@ -0,0 +1,205 @@
+use futures::executor::{block_on, PanicPolicy, ThreadPool};
+use futures::future::{self, lazy, Future};
+use futures::task::{Context, Poll, Spawn, SpawnExt};
+use std::pin::Pin;
+use std::sync::{mpsc, Arc, Barrier, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[test]
+fn worker_survives_task_panic() {
+    let (tx, rx) = mpsc::channel();
+    let tx = Mutex::new(tx);
+    let mut pool = ThreadPool::builder()
+        .pool_size(1)
+        .panic_handler(move |payload| {
+            let msg = payload.downcast_ref::<&str>().map(|msg| msg.to_string());
+            tx.lock().unwrap().send(msg).unwrap();
+        })
+        .create()
+        .unwrap();
+
+    pool.spawn(lazy(|_| panic!("boom"))).unwrap();
+    assert_eq!(rx.recv().unwrap(), Some("boom".to_string()));
+
+    let handle = pool.spawn_with_handle(future::ready(5)).unwrap();
+    assert_eq!(block_on(handle), 5);
+}
+
+#[test]
+fn worker_survives_panic_while_dropping_panicked_task() {
+    struct PanicOnDrop;
+
+    impl Future for PanicOnDrop {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+            panic!("poll")
+        }
+    }
+
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            panic!("drop")
+        }
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let tx = Mutex::new(tx);
+    let mut pool = ThreadPool::builder()
+        .pool_size(1)
+        .panic_handler(move |payload| {
+            let msg = payload.downcast_ref::<&str>().map(|msg| msg.to_string());
+            tx.lock().unwrap().send(msg).unwrap();
+        })
+        .create()
+        .unwrap();
+
+    pool.spawn(PanicOnDrop).unwrap();
+    assert_eq!(rx.recv().unwrap(), Some("poll".to_string()));
+    assert_eq!(rx.recv().unwrap(), Some("drop".to_string()));
+
+    let handle = pool.spawn_with_handle(future::ready(5)).unwrap();
+    assert_eq!(block_on(handle), 5);
+}
+
+#[test]
+fn shutdown_on_task_panic() {
+    let mut pool = ThreadPool::builder()
+        .pool_size(2)
+        .panic_policy(PanicPolicy::Shutdown)
+        .create()
+        .unwrap();
+    assert!(pool.status().is_ok());
+
+    pool.spawn(lazy(|_| panic!("boom"))).unwrap();
+    for _ in 0..1000 {
+        if pool.status().is_err() {
+            break;
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+    assert!(pool.status().unwrap_err().is_shutdown());
+    assert!(pool.spawn(future::ready(())).unwrap_err().is_shutdown());
+}
+
+#[test]
+fn grows_under_load_and_shrinks_when_idle() {
+    let (start_tx, start_rx) = mpsc::channel();
+    let (stop_tx, stop_rx) = mpsc::channel();
+    let (start_tx, stop_tx) = (Mutex::new(start_tx), Mutex::new(stop_tx));
+    let mut pool = ThreadPool::builder()
+        .min_threads(1)
+        .max_threads(3)
+        .keep_alive(Duration::from_millis(100))
+        .after_start(move |idx| start_tx.lock().unwrap().send(idx).unwrap())
+        .before_stop(move |idx| stop_tx.lock().unwrap().send(idx).unwrap())
+        .create()
+        .unwrap();
+    assert_eq!(pool.num_threads(), 1);
+
+    // Each task blocks its worker until all three run at the same time.
+    let barrier = Arc::new(Barrier::new(4));
+    for _ in 0..3 {
+        let barrier = barrier.clone();
+        pool.spawn(lazy(move |_| { barrier.wait(); })).unwrap();
+    }
+    barrier.wait();
+    assert_eq!(pool.num_threads(), 3);
+    let mut started = (0..3).map(|_| start_rx.recv().unwrap()).collect::<Vec<_>>();
+    started.sort();
+    assert_eq!(started, vec![0, 1, 2]);
+
+    for _ in 0..2 {
+        stop_rx.recv_timeout(Duration::from_secs(10)).unwrap();
+    }
+    wait_for_threads(&pool, 1);
+    assert!(stop_rx.recv_timeout(Duration::from_millis(100)).is_err());
+}
+
+#[test]
+fn idle_workers_exit_together() {
+    let (stop_tx, stop_rx) = mpsc::channel();
+    let stop_tx = Mutex::new(stop_tx);
+    let mut pool = ThreadPool::builder()
+        .min_threads(1)
+        .max_threads(6)
+        .keep_alive(Duration::from_secs(1))
+        .before_stop(move |_| stop_tx.lock().unwrap().send(Instant::now()).unwrap())
+        .create()
+        .unwrap();
+
+    let barrier = Arc::new(Barrier::new(7));
+    for _ in 0..6 {
+        let barrier = barrier.clone();
+        pool.spawn(lazy(move |_| { barrier.wait(); })).unwrap();
+    }
+    barrier.wait();
+    let idle_since = Instant::now();
+    assert_eq!(pool.num_threads(), 6);
+
+    // Workers exiting one after the other would take five keep-alive periods.
+    let stopped = (0..5)
+        .map(|_| stop_rx.recv_timeout(Duration::from_secs(30)).unwrap())
+        .max()
+        .unwrap();
+    assert!(stopped.duration_since(idle_since) < Duration::from_secs(4));
+    wait_for_threads(&pool, 1);
+}
+
+fn wait_for_threads(pool: &ThreadPool, threads: usize) {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while pool.num_threads() != threads {
+        assert!(Instant::now() < deadline, "pool still has {} threads", pool.num_threads());
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn resize() {
+    let (start_tx, start_rx) = mpsc::channel();
+    let (stop_tx, stop_rx) = mpsc::channel();
+    let (start_tx, stop_tx) = (Mutex::new(start_tx), Mutex::new(stop_tx));
+    let mut pool = ThreadPool::builder()
+        .pool_size(2)
+        .after_start(move |idx| start_tx.lock().unwrap().send(idx).unwrap())
+        .before_stop(move |idx| stop_tx.lock().unwrap().send(idx).unwrap())
+        .create()
+        .unwrap();
+
+    pool.resize(4).unwrap();
+    assert_eq!(pool.num_threads(), 4);
+    for _ in 0..4 {
+        start_rx.recv_timeout(Duration::from_secs(10)).unwrap();
+    }
+
+    pool.resize(1).unwrap();
+    assert_eq!(pool.num_threads(), 1);
+    for _ in 0..3 {
+        stop_rx.recv_timeout(Duration::from_secs(10)).unwrap();
+    }
+
+    let handle = pool.spawn_with_handle(future::ready(1)).unwrap();
+    assert_eq!(block_on(handle), 1);
+    drop(pool);
+    stop_rx.recv_timeout(Duration::from_secs(10)).unwrap();
+}
+
+#[test]
+fn busy_task_does_not_starve_others() {
+    use futures::channel::mpsc;
+    use futures::stream::StreamExt;
+
+    let mut pool = ThreadPool::builder().pool_size(1).create().unwrap();
+
+    // A stream which is always ready, as it is fed by its own consumer.
+    let (tx, rx) = mpsc::unbounded();
+    tx.unbounded_send(()).unwrap();
+    pool.spawn(rx.for_each(move |()| {
+        tx.unbounded_send(()).unwrap();
+        future::ready(())
+    })).unwrap();
+
+    let handle = pool.spawn_with_handle(future::ready(1)).unwrap();
+    assert_eq!(block_on(handle), 1);
+}
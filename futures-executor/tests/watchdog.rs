@@ -0,0 +1,113 @@
+use futures::build_local_task;
+use futures::channel::oneshot;
+use futures::executor::{block_on, LocalPool, TaskState, ThreadPool, Watchdog};
+use futures::future::{self, FutureExt};
+use futures::task::{LocalSpawnExt, SpawnExt};
+use std::sync::mpsc;
+use std::time::Duration;
+
+#[test]
+fn reports_tasks_waiting_for_a_wakeup() {
+    let watchdog = Watchdog::new(Duration::from_secs(0));
+    let mut pool = LocalPool::builder().watchdog(watchdog.clone()).create();
+    let mut spawner = pool.spawner();
+
+    let (tx, rx) = oneshot::channel::<()>();
+    spawner.build_local_task().name("waiting").spawn_local(rx.map(|_| ())).unwrap();
+    spawner.build_local_task().name("done").spawn_local(future::ready(())).unwrap();
+    assert!(watchdog.stalled().is_empty());
+
+    pool.run_until_stalled();
+    let stalled = watchdog.stalled();
+    assert_eq!(stalled.len(), 1);
+    assert_eq!(stalled[0].name(), Some("waiting"));
+    assert_eq!(stalled[0].state(), TaskState::Idle);
+    assert!(stalled[0].last_polled().is_some());
+    assert!(stalled[0].last_woken().is_none());
+
+    tx.send(()).unwrap();
+    assert_eq!(watchdog.tasks()[0].state(), TaskState::Scheduled);
+    assert!(watchdog.tasks()[0].last_woken().is_some());
+    assert!(watchdog.stalled().is_empty());
+
+    pool.run_until_stalled();
+    assert!(watchdog.tasks().is_empty());
+}
+
+#[test]
+fn threshold_is_respected() {
+    let watchdog = Watchdog::new(Duration::from_secs(3600));
+    let mut pool = LocalPool::builder().watchdog(watchdog.clone()).create();
+    pool.spawner().spawn_local(future::pending()).unwrap();
+    pool.run_until_stalled();
+
+    assert_eq!(watchdog.tasks().len(), 1);
+    assert!(watchdog.stalled().is_empty());
+}
+
+#[test]
+fn reports_spawn_location() {
+    let watchdog = Watchdog::new(Duration::from_secs(0));
+    let mut pool = LocalPool::builder().watchdog(watchdog.clone()).create();
+    let mut spawner = pool.spawner();
+
+    let line = line!() + 1;
+    spawner.build_local_task().location(file!(), line).spawn_local(future::pending()).unwrap();
+    spawner.spawn_local(future::pending()).unwrap();
+    pool.run_until_stalled();
+
+    let stalled = watchdog.stalled();
+    let location = stalled[0].location().unwrap();
+    assert_eq!(location.file(), file!());
+    assert_eq!(location.line(), line);
+    assert!(stalled[0].to_string().contains(&format!("spawned at {}:{}", file!(), line)));
+    assert_eq!(stalled[1].location(), None);
+}
+
+#[test]
+fn build_task_macro_records_spawn_location() {
+    let watchdog = Watchdog::new(Duration::from_secs(0));
+    let mut pool = LocalPool::builder().watchdog(watchdog.clone()).create();
+    let mut spawner = pool.spawner();
+
+    let line = line!() + 1;
+    build_local_task!(spawner).spawn_local(future::pending()).unwrap();
+    pool.run_until_stalled();
+
+    let stalled = watchdog.stalled();
+    assert!(stalled[0].to_string().contains(&format!("spawned at {}:{}", file!(), line)));
+}
+
+#[test]
+fn dropped_tasks_are_not_live() {
+    let watchdog = Watchdog::new(Duration::from_secs(0));
+    let mut pool = LocalPool::builder().watchdog(watchdog.clone()).create();
+    pool.spawner().spawn_local(future::pending()).unwrap();
+    pool.run_until_stalled();
+    assert_eq!(watchdog.tasks().len(), 1);
+
+    drop(pool);
+    assert!(watchdog.tasks().is_empty());
+}
+
+#[test]
+fn thread_pool_monitor() {
+    let watchdog = Watchdog::new(Duration::from_millis(10));
+    let pool = ThreadPool::builder().pool_size(1).watchdog(watchdog.clone()).create().unwrap();
+
+    let (report_tx, report_rx) = mpsc::channel();
+    watchdog.spawn_monitor(Duration::from_millis(5), move |stalled| {
+        let names = stalled.iter().map(|task| task.name().map(String::from)).collect::<Vec<_>>();
+        let _ = report_tx.send(names);
+    }).unwrap();
+
+    let (tx, rx) = oneshot::channel::<()>();
+    let handle = pool.clone().build_task().name("lost").spawn_with_handle(rx.map(|_| ())).unwrap();
+
+    let names = report_rx.recv_timeout(Duration::from_secs(10)).unwrap();
+    assert_eq!(names, vec![Some("lost".to_string())]);
+    assert!(watchdog.stalled()[0].to_string().contains("\"lost\""));
+
+    tx.send(()).unwrap();
+    block_on(handle);
+}
@@ -1,30 +1,54 @@
 use crate::task::AtomicWaker;
 use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Context, Poll};
 use pin_utils::unsafe_pinned;
+use core::cell::UnsafeCell;
+use core::fmt;
 use core::pin::Pin;
-use core::sync::atomic::{AtomicBool, Ordering};
+use core::sync::atomic::{AtomicUsize, Ordering};
 use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use alloc::sync::Weak;
 
-/// A future which can be remotely short-circuited using an `AbortHandle`.
-#[derive(Debug, Clone)]
+/// A future or stream which can be remotely short-circuited using an
+/// `AbortHandle`.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
-pub struct Abortable<Fut> {
-    future: Fut,
-    inner: Arc<AbortInner>,
+pub struct Abortable<T, E = Aborted> {
+    task: T,
+    inner: Arc<AbortInner<E>>,
 }
 
-impl<Fut: Unpin> Unpin for Abortable<Fut> {}
+impl<T: Unpin, E> Unpin for Abortable<T, E> {}
 
-impl<Fut> Abortable<Fut> where Fut: Future {
-    unsafe_pinned!(future: Fut);
+impl<T: fmt::Debug, E> fmt::Debug for Abortable<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Abortable")
+            .field("task", &self.task)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T: Clone, E> Clone for Abortable<T, E> {
+    fn clone(&self) -> Self {
+        Abortable {
+            task: self.task.clone(),
+            inner: self.inner.clone(),
+        }
+    }
+}
 
-    /// Creates a new `Abortable` future using an existing `AbortRegistration`.
-    /// `AbortRegistration`s can be acquired through `AbortHandle::new`.
+impl<T, E> Abortable<T, E> {
+    unsafe_pinned!(task: T);
+
+    /// Creates a new `Abortable` future or stream using an existing
+    /// `AbortRegistration`. `AbortRegistration`s can be acquired through
+    /// `AbortHandle::new`.
     ///
     /// When `abort` is called on the handle tied to `reg` or if `abort` has
     /// already been called, the future will complete immediately without making
-    /// any further progress.
+    /// any further progress. A stream will end instead.
     ///
     /// Example:
     ///
@@ -39,26 +63,55 @@ impl<Fut> Abortable<Fut> where Fut: Future {
     /// assert_eq!(future.await, Err(Aborted));
     /// # });
     /// ```
-    pub fn new(future: Fut, reg: AbortRegistration) -> Self {
+    pub fn new(task: T, reg: AbortRegistration<E>) -> Self {
         Abortable {
-            future,
+            task,
             inner: reg.inner,
         }
     }
+
+    /// Checks whether the task has been aborted. Note that all this
+    /// method indicates is whether `abort` or `abort_with` was called on the
+    /// handle. It does not guarantee that the task has stopped running.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.is_aborted()
+    }
 }
 
 /// A registration handle for a `Abortable` future.
 /// Values of this type can be acquired from `AbortHandle::new` and are used
 /// in calls to `Abortable::new`.
-#[derive(Debug)]
-pub struct AbortRegistration {
-    inner: Arc<AbortInner>,
+pub struct AbortRegistration<E = Aborted> {
+    inner: Arc<AbortInner<E>>,
+}
+
+impl<E> fmt::Debug for AbortRegistration<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AbortRegistration")
+            .field("inner", &self.inner)
+            .finish()
+    }
 }
 
 /// A handle to a `Abortable` future.
-#[derive(Debug, Clone)]
-pub struct AbortHandle {
-    inner: Arc<AbortInner>,
+pub struct AbortHandle<E = Aborted> {
+    inner: Arc<AbortInner<E>>,
+}
+
+impl<E> fmt::Debug for AbortHandle<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AbortHandle")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<E> Clone for AbortHandle<E> {
+    fn clone(&self) -> Self {
+        AbortHandle {
+            inner: self.inner.clone(),
+        }
+    }
 }
 
 impl AbortHandle {
@@ -81,9 +134,50 @@ impl AbortHandle {
     /// # });
     /// ```
     pub fn new_pair() -> (Self, AbortRegistration) {
+        AbortHandle::new_pair_inner()
+    }
+}
+
+impl<R> AbortHandle<AbortedWith<R>> {
+    /// Creates an (`AbortHandle`, `AbortRegistration`) pair whose handle can
+    /// abort the future with a reason of type `R`.
+    ///
+    /// The reason passed to [`abort_with`](AbortHandle::abort_with) is
+    /// returned in the `AbortedWith` error of the future.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{pending, Abortable, AbortHandle};
+    ///
+    /// let (abort_handle, abort_registration) = AbortHandle::new_pair_with_reason();
+    /// let future = Abortable::new(pending::<()>(), abort_registration);
+    /// abort_handle.abort_with("shutting down");
+    /// assert_eq!(future.await.unwrap_err().reason(), Some(&"shutting down"));
+    /// # });
+    /// ```
+    pub fn new_pair_with_reason() -> (Self, AbortRegistration<AbortedWith<R>>) {
+        AbortHandle::new_pair_inner()
+    }
+
+    /// Abort the `Abortable` future associated with this handle, passing
+    /// `reason` back through its `AbortedWith` error.
+    ///
+    /// If the future has already been aborted, this call has no effect and
+    /// `reason` is dropped.
+    pub fn abort_with(&self, reason: R) {
+        self.inner.abort(Some(AbortedWith { reason: Some(reason) }));
+    }
+}
+
+impl<E> AbortHandle<E> {
+    fn new_pair_inner() -> (Self, AbortRegistration<E>) {
         let inner = Arc::new(AbortInner {
             waker: AtomicWaker::new(),
-            cancel: AtomicBool::new(false),
+            state: AtomicUsize::new(RUNNING),
+            reason: UnsafeCell::new(None),
         });
 
         (
@@ -97,12 +191,61 @@ impl AbortHandle {
     }
 }
 
-// Inner type storing the waker to awaken and a bool indicating that it
-// should be cancelled.
-#[derive(Debug)]
-struct AbortInner {
+const RUNNING: usize = 0;
+const ABORTING: usize = 1;
+const ABORTED: usize = 2;
+
+// Inner type storing the waker to awaken, the abort state and the reason the
+// task was aborted with.
+//
+// `reason` is written once while moving from `RUNNING` to `ABORTING`, and is
+// only read after `ABORTED` has been observed.
+struct AbortInner<E> {
     waker: AtomicWaker,
-    cancel: AtomicBool,
+    state: AtomicUsize,
+    reason: UnsafeCell<Option<E>>,
+}
+
+unsafe impl<E: Send + Sync> Sync for AbortInner<E> {}
+
+impl<E> fmt::Debug for AbortInner<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AbortInner")
+            .field("waker", &self.waker)
+            .field("is_aborted", &self.is_aborted())
+            .finish()
+    }
+}
+
+impl<E> AbortInner<E> {
+    fn is_aborted(&self) -> bool {
+        self.state.load(Ordering::Relaxed) != RUNNING
+    }
+
+    // Whether the reason has been fully written and the task may observe
+    // that it was aborted.
+    fn is_complete(&self) -> bool {
+        self.state.load(Ordering::Acquire) == ABORTED
+    }
+
+    fn aborted(&self) -> Option<E> where E: Clone + Default {
+        if self.is_complete() {
+            // The reason is never written again once `ABORTED` was stored.
+            let reason = unsafe { (*self.reason.get()).clone() };
+            Some(reason.unwrap_or_default())
+        } else {
+            None
+        }
+    }
+
+    fn abort(&self, reason: Option<E>) {
+        // Only the first call gets to set the reason.
+        if self.state.compare_exchange(RUNNING, ABORTING, Ordering::Acquire, Ordering::Relaxed).is_ok() {
+            unsafe { *self.reason.get() = reason; }
+            self.state.store(ABORTED, Ordering::Release);
+            self.waker.wake();
+        }
+    }
 }
 
 /// Creates a new `Abortable` future and a `AbortHandle` which can be used to stop it.
@@ -123,20 +266,52 @@ pub fn abortable<Fut>(future: Fut) -> (Abortable<Fut>, AbortHandle)
 }
 
 /// Indicator that the `Abortable` future was aborted.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub struct Aborted;
 
-impl<Fut> Future for Abortable<Fut> where Fut: Future {
-    type Output = Result<Fut::Output, Aborted>;
+/// Indicator that an `Abortable` future created through
+/// [`new_pair_with_reason`](AbortHandle::new_pair_with_reason) was aborted.
+///
+/// If the future was aborted through
+/// [`abort_with`](AbortHandle::abort_with), the reason it was given can be
+/// retrieved from this value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct AbortedWith<R> {
+    reason: Option<R>,
+}
+
+impl<R> Default for AbortedWith<R> {
+    fn default() -> Self {
+        AbortedWith { reason: None }
+    }
+}
+
+impl<R> AbortedWith<R> {
+    /// Returns the reason passed to
+    /// [`abort_with`](AbortHandle::abort_with), or `None` if the future was
+    /// aborted through [`abort`](AbortHandle::abort).
+    pub fn reason(&self) -> Option<&R> {
+        self.reason.as_ref()
+    }
+
+    /// Consumes this error, returning the reason passed to
+    /// [`abort_with`](AbortHandle::abort_with), if any.
+    pub fn into_reason(self) -> Option<R> {
+        self.reason
+    }
+}
+
+impl<Fut, E> Future for Abortable<Fut, E> where Fut: Future, E: Clone + Default {
+    type Output = Result<Fut::Output, E>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         // Check if the future has been aborted
-        if self.inner.cancel.load(Ordering::Relaxed) {
-            return Poll::Ready(Err(Aborted))
+        if let Some(aborted) = self.inner.aborted() {
+            return Poll::Ready(Err(aborted))
         }
 
         // attempt to complete the future
-        if let Poll::Ready(x) = self.as_mut().future().poll(cx) {
+        if let Poll::Ready(x) = self.as_mut().task().poll(cx) {
             return Poll::Ready(Ok(x))
         }
 
@@ -145,17 +320,53 @@ impl<Fut> Future for Abortable<Fut> where Fut: Future {
 
         // Check to see if the future was aborted between the first check and
         // registration.
-        // Checking with `Relaxed` is sufficient because `register` introduces an
-        // `AcqRel` barrier.
-        if self.inner.cancel.load(Ordering::Relaxed) {
-            return Poll::Ready(Err(Aborted))
+        if let Some(aborted) = self.inner.aborted() {
+            return Poll::Ready(Err(aborted))
         }
 
         Poll::Pending
     }
 }
 
-impl AbortHandle {
+impl<St, E> Stream for Abortable<St, E> where St: Stream {
+    type Item = St::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        // An aborted stream ends, dropping any items it did not yield yet.
+        if self.inner.is_complete() {
+            return Poll::Ready(None)
+        }
+
+        if let Poll::Ready(item) = self.as_mut().task().poll_next(cx) {
+            return Poll::Ready(item)
+        }
+
+        self.inner.waker.register(cx.waker());
+
+        if self.inner.is_complete() {
+            return Poll::Ready(None)
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The stream may be aborted at any time.
+        if self.inner.is_complete() {
+            (0, Some(0))
+        } else {
+            (0, self.task.size_hint().1)
+        }
+    }
+}
+
+impl<St, E> FusedStream for Abortable<St, E> where St: FusedStream {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_complete() || self.task.is_terminated()
+    }
+}
+
+impl<E> AbortHandle<E> {
     /// Abort the `Abortable` future associated with this handle.
     ///
     /// Notifies the Abortable future associated with this handle that it
@@ -163,7 +374,36 @@ impl AbortHandle {
     /// another thread, it will not immediately stop running. Instead, it will
     /// continue to run until its poll method returns.
     pub fn abort(&self) {
-        self.inner.cancel.store(true, Ordering::Relaxed);
-        self.inner.waker.wake();
+        self.inner.abort(None);
+    }
+
+    /// Checks whether [`abort`](AbortHandle::abort) or
+    /// [`abort_with`](AbortHandle::abort_with) has been called on this
+    /// handle or any of its clones.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.is_aborted()
+    }
+}
+
+impl AbortHandle {
+    #[cfg(feature = "std")]
+    pub(crate) fn downgrade(&self) -> WeakAbortHandle {
+        WeakAbortHandle {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+}
+
+/// A handle which does not keep its `Abortable` future's state alive.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub(crate) struct WeakAbortHandle {
+    inner: Weak<AbortInner<Aborted>>,
+}
+
+#[cfg(feature = "std")]
+impl WeakAbortHandle {
+    pub(crate) fn upgrade(&self) -> Option<AbortHandle> {
+        self.inner.upgrade().map(|inner| AbortHandle { inner })
     }
 }
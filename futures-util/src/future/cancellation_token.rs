@@ -0,0 +1,283 @@
+use super::abortable::{AbortHandle, AbortRegistration, WeakAbortHandle};
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll, Waker};
+use slab::Slab;
+use std::fmt;
+use std::mem;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, Weak};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A token which can be used to signal cancellation to a tree of tasks.
+///
+/// Tokens are cheap to clone, and all clones share the same cancellation
+/// state. A token can also be forked into child tokens with
+/// [`child_token`](CancellationToken::child_token). Cancelling a token
+/// cancels all of its descendants, but cancelling a child token has no
+/// effect on its parent.
+///
+/// Futures can be tied to a token through
+/// [`abort_registration`](CancellationToken::abort_registration), so that
+/// cancelling the token aborts them.
+///
+/// Example:
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{pending, Abortable, Aborted, CancellationToken};
+///
+/// let root = CancellationToken::new();
+/// let child = root.child_token();
+/// let request = Abortable::new(pending::<()>(), child.abort_registration());
+///
+/// root.cancel();
+/// assert!(child.is_cancelled());
+/// assert_eq!(request.await, Err(Aborted));
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    cancelled: AtomicBool,
+    state: Mutex<State>,
+    // The parent token and this token's key in the parent's `children`.
+    parent: Option<(Arc<Inner>, usize)>,
+}
+
+#[derive(Default)]
+struct State {
+    wakers: Slab<Waker>,
+    children: Slab<Weak<Inner>>,
+    aborts: Vec<WeakAbortHandle>,
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("is_cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> CancellationToken {
+        CancellationToken::new()
+    }
+}
+
+impl CancellationToken {
+    /// Creates a new root `CancellationToken` which has not been cancelled.
+    pub fn new() -> CancellationToken {
+        CancellationToken {
+            inner: Arc::new(Inner {
+                cancelled: AtomicBool::new(false),
+                state: Mutex::new(State::default()),
+                parent: None,
+            }),
+        }
+    }
+
+    /// Creates a child token of this token.
+    ///
+    /// The child is cancelled when this token (or any of its ancestors) is
+    /// cancelled. Cancelling the child does not affect this token. If this
+    /// token has already been cancelled, the returned token is cancelled as
+    /// well.
+    pub fn child_token(&self) -> CancellationToken {
+        let mut state = self.inner.state.lock().unwrap();
+        let inner = if self.is_cancelled() {
+            Arc::new(Inner {
+                cancelled: AtomicBool::new(true),
+                state: Mutex::new(State::default()),
+                parent: None,
+            })
+        } else {
+            let entry = state.children.vacant_entry();
+            let inner = Arc::new(Inner {
+                cancelled: AtomicBool::new(false),
+                state: Mutex::new(State::default()),
+                parent: Some((self.inner.clone(), entry.key())),
+            });
+            entry.insert(Arc::downgrade(&inner));
+            inner
+        };
+        CancellationToken { inner }
+    }
+
+    /// Cancels this token and all of its descendants.
+    ///
+    /// All tasks waiting on [`cancelled`](CancellationToken::cancelled) are
+    /// woken, and all futures registered through
+    /// [`abort_registration`](CancellationToken::abort_registration) are
+    /// aborted. Calling this method on a token which has already been
+    /// cancelled has no effect.
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// Returns `true` if this token has been cancelled, either directly or
+    /// through one of its ancestors.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns a future which completes once this token has been cancelled.
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled {
+            token: Some(self),
+            wait_key: WAIT_KEY_NONE,
+        }
+    }
+
+    /// Creates an `AbortRegistration` which is aborted when this token is
+    /// cancelled.
+    ///
+    /// The registration can be passed to
+    /// [`Abortable::new`](crate::future::Abortable::new) to make a future
+    /// part of this token's cancellation tree.
+    pub fn abort_registration(&self) -> AbortRegistration {
+        let (handle, reg) = AbortHandle::new_pair();
+        let mut state = self.inner.state.lock().unwrap();
+        if self.is_cancelled() {
+            handle.abort();
+        } else {
+            // Forget about futures which have already been dropped so that
+            // long-lived tokens don't accumulate dead handles.
+            state.aborts.retain(|handle| handle.upgrade().is_some());
+            state.aborts.push(handle.downgrade());
+        }
+        reg
+    }
+}
+
+impl Inner {
+    fn cancel(&self) {
+        if self.cancelled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        // Take everything out of the lock before notifying anyone, as child
+        // tokens lock their parent when they are dropped.
+        let mut state = mem::replace(&mut *self.state.lock().unwrap(), State::default());
+        for waker in state.wakers.drain() {
+            waker.wake();
+        }
+        for handle in state.aborts.drain(..) {
+            if let Some(handle) = handle.upgrade() {
+                handle.abort();
+            }
+        }
+        for child in state.children.drain() {
+            if let Some(child) = child.upgrade() {
+                child.cancel();
+            }
+        }
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        if let Some((parent, key)) = &self.parent {
+            // Futures registered with this token may outlive it, so hand them
+            // over to the parent to keep them reachable from the tree.
+            let mut aborts = mem::replace(&mut self.state.get_mut().unwrap().aborts, Vec::new());
+            aborts.retain(|handle| handle.upgrade().is_some());
+
+            let mut state = parent.state.lock().unwrap();
+            // The parent drains its children when it is cancelled, and never
+            // registers new ones afterwards.
+            if state.children.contains(*key) {
+                state.children.remove(*key);
+            }
+            if !parent.cancelled.load(Ordering::SeqCst) {
+                state.aborts.append(&mut aborts);
+                return;
+            }
+            drop(state);
+
+            for handle in aborts {
+                if let Some(handle) = handle.upgrade() {
+                    handle.abort();
+                }
+            }
+        }
+    }
+}
+
+// Sentinel for when no slot in the `Slab` has been dedicated to this object.
+const WAIT_KEY_NONE: usize = usize::max_value();
+
+/// Future for the [`cancelled`](CancellationToken::cancelled) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Cancelled<'a> {
+    // `None` indicates that the token has been cancelled.
+    token: Option<&'a CancellationToken>,
+    wait_key: usize,
+}
+
+impl fmt::Debug for Cancelled<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cancelled")
+            .field("token", &self.token)
+            .finish()
+    }
+}
+
+impl Cancelled<'_> {
+    fn remove_waker(&mut self, token: &CancellationToken) {
+        if self.wait_key != WAIT_KEY_NONE {
+            let mut state = token.inner.state.lock().unwrap();
+            if state.wakers.contains(self.wait_key) {
+                state.wakers.remove(self.wait_key);
+            }
+            self.wait_key = WAIT_KEY_NONE;
+        }
+    }
+}
+
+impl FusedFuture for Cancelled<'_> {
+    fn is_terminated(&self) -> bool {
+        self.token.is_none()
+    }
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let token = self.token.expect("polled Cancelled after completion");
+
+        if !token.is_cancelled() {
+            let mut state = token.inner.state.lock().unwrap();
+            // Check again while holding the lock, as `cancel` drains the
+            // wakers only after setting the flag.
+            if !token.is_cancelled() {
+                if self.wait_key == WAIT_KEY_NONE {
+                    self.wait_key = state.wakers.insert(cx.waker().clone());
+                } else {
+                    let waker = &mut state.wakers[self.wait_key];
+                    if !waker.will_wake(cx.waker()) {
+                        *waker = cx.waker().clone();
+                    }
+                }
+                return Poll::Pending;
+            }
+        }
+
+        self.remove_waker(token);
+        self.token = None;
+        Poll::Ready(())
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(token) = self.token {
+            self.remove_waker(token);
+        }
+    }
+}
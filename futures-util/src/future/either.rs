@@ -80,6 +80,13 @@ where
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Either::Left(a) => a.size_hint(),
+            Either::Right(b) => b.size_hint(),
+        }
+    }
 }
 
 impl<A, B, Item> Sink<Item> for Either<A, B>
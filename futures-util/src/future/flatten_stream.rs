@@ -86,4 +86,11 @@ impl<Fut> Stream for FlattenStream<Fut>
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.state {
+            State::Future(_) => (0, None),
+            State::Stream(stream) => stream.size_hint(),
+        }
+    }
 }
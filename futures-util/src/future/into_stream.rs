@@ -33,4 +33,12 @@ impl<Fut: Future> Stream for IntoStream<Fut> {
         self.as_mut().future().set(None);
         Poll::Ready(Some(v))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.future.is_some() {
+            (1, Some(1))
+        } else {
+            (0, Some(0))
+        }
+    }
 }
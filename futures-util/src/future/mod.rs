@@ -92,7 +92,12 @@ cfg_target_has_atomic! {
     #[cfg(feature = "alloc")]
     mod abortable;
     #[cfg(feature = "alloc")]
-    pub use self::abortable::{abortable, Abortable, AbortHandle, AbortRegistration, Aborted};
+    pub use self::abortable::{abortable, Abortable, AbortHandle, AbortRegistration, Aborted, AbortedWith};
+
+    #[cfg(feature = "std")]
+    mod cancellation_token;
+    #[cfg(feature = "std")]
+    pub use self::cancellation_token::{CancellationToken, Cancelled};
 }
 
 #[cfg(feature = "std")]
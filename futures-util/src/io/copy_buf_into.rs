@@ -1,5 +1,5 @@
 use futures_core::future::Future;
-use futures_core::task::{Context, Poll};
+use futures_core::task::{poll_budget, Context, Poll};
 use futures_io::{AsyncBufRead, AsyncWrite};
 use std::io;
 use std::pin::Pin;
@@ -43,6 +43,7 @@ impl<R, W> Future for CopyBufInto<'_, R, W>
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let (mut reader, mut writer, amt) = self.project();
         loop {
+            ready!(poll_budget(cx));
             let buffer = ready!(reader.as_mut().poll_fill_buf(cx))?;
             if buffer.is_empty() {
                 ready!(writer.as_mut().poll_flush(cx))?;
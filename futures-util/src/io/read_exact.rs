@@ -1,6 +1,6 @@
 use crate::io::AsyncRead;
 use futures_core::future::Future;
-use futures_core::task::{Context, Poll};
+use futures_core::task::{poll_budget, Context, Poll};
 use std::io;
 use std::mem;
 use std::pin::Pin;
@@ -27,6 +27,7 @@ impl<R: AsyncRead + ?Sized + Unpin> Future for ReadExact<'_, R> {
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = &mut *self;
         while !this.buf.is_empty() {
+            ready!(poll_budget(cx));
             let n = ready!(Pin::new(&mut this.reader).poll_read(cx, this.buf))?;
             {
                 let (_, rest) = mem::replace(&mut this.buf, &mut []).split_at_mut(n);
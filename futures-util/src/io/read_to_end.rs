@@ -1,5 +1,5 @@
 use futures_core::future::Future;
-use futures_core::task::{Context, Poll};
+use futures_core::task::{poll_budget, Context, Poll};
 use futures_io::AsyncRead;
 use std::io;
 use std::pin::Pin;
@@ -46,6 +46,7 @@ fn read_to_end_internal<R: AsyncRead + ?Sized>(
     let mut g = Guard { len: buf.len(), buf };
     let ret;
     loop {
+        ready!(poll_budget(cx));
         if g.len == g.buf.len() {
             unsafe {
                 g.buf.reserve(32);
@@ -1,5 +1,5 @@
 use futures_core::future::Future;
-use futures_core::task::{Context, Poll};
+use futures_core::task::{poll_budget, Context, Poll};
 use futures_io::AsyncBufRead;
 use std::io;
 use std::mem;
@@ -31,6 +31,7 @@ pub(super) fn read_until_internal<R: AsyncBufRead + ?Sized>(
     cx: &mut Context<'_>,
 ) -> Poll<io::Result<usize>> {
     loop {
+        ready!(poll_budget(cx));
         let (done, used) = {
             let available = ready!(reader.as_mut().poll_fill_buf(cx))?;
             if let Some(i) = memchr::memchr(byte, available) {
@@ -1,5 +1,5 @@
 use futures_core::future::Future;
-use futures_core::task::{Context, Poll};
+use futures_core::task::{poll_budget, Context, Poll};
 use futures_io::AsyncWrite;
 use std::io;
 use std::mem;
@@ -27,6 +27,7 @@ impl<W: AsyncWrite + ?Sized + Unpin> Future for WriteAll<'_, W> {
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         let this = &mut *self;
         while !this.buf.is_empty() {
+            ready!(poll_budget(cx));
             let n = ready!(Pin::new(&mut this.writer).poll_write(cx, this.buf))?;
             {
                 let (_, rest) = mem::replace(&mut this.buf, &[]).split_at(n);
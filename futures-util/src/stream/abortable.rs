@@ -0,0 +1,35 @@
+use crate::future::{AbortHandle, Abortable};
+use futures_core::stream::Stream;
+
+/// Creates a new `Abortable` stream and a `AbortHandle` which can be used to stop it.
+///
+/// The stream ends once `abort` is called on the handle, without yielding any
+/// further items from the underlying stream.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// Example:
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt};
+///
+/// let (mut stream, abort_handle) = stream::abortable(stream::iter(1..=3));
+/// assert_eq!(stream.next().await, Some(1));
+///
+/// abort_handle.abort();
+/// assert!(abort_handle.is_aborted());
+/// assert_eq!(stream.next().await, None);
+/// # });
+/// ```
+pub fn abortable<St>(stream: St) -> (Abortable<St>, AbortHandle)
+    where St: Stream
+{
+    let (handle, reg) = AbortHandle::new_pair();
+    (
+        Abortable::new(stream, reg),
+        handle,
+    )
+}
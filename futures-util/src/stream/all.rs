@@ -0,0 +1,84 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`all`](super::StreamExt::all) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct All<St, Fut, F> {
+    stream: St,
+    f: F,
+    future: Option<Fut>,
+    done: bool,
+}
+
+impl<St: Unpin, Fut: Unpin, F> Unpin for All<St, Fut, F> {}
+
+impl<St, Fut, F> fmt::Debug for All<St, Fut, F>
+where
+    St: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("All")
+            .field("stream", &self.stream)
+            .field("future", &self.future)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> All<St, Fut, F>
+    where St: Stream,
+          F: FnMut(St::Item) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(future: Option<Fut>);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, f: F) -> All<St, Fut, F> {
+        All { stream, f, future: None, done: false }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for All<St, Fut, F> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St, Fut, F> Future for All<St, Fut, F>
+    where St: Stream,
+          F: FnMut(St::Item) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    type Output = bool;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<bool> {
+        loop {
+            if let Some(future) = self.as_mut().future().as_pin_mut() {
+                let matched = ready!(future.poll(cx));
+                self.as_mut().future().set(None);
+                if !matched {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(false);
+                }
+            }
+
+            match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => {
+                    let future = (self.as_mut().f())(item);
+                    self.as_mut().future().set(Some(future));
+                }
+                None => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(true);
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,7 @@
 use crate::stream::{Fuse, FuturesUnordered, StreamExt};
+use crate::stream::concurrency_limit::Limit;
+#[cfg(feature = "std")]
+use crate::stream::concurrency_limit::ConcurrencyLimit;
 use futures_core::future::Future;
 use futures_core::stream::{Stream, FusedStream};
 use futures_core::task::{Context, Poll};
@@ -17,7 +20,7 @@ where
 {
     stream: Fuse<St>,
     in_progress_queue: FuturesUnordered<St::Item>,
-    max: usize,
+    max: Limit,
 }
 
 impl<St> Unpin for BufferUnordered<St>
@@ -56,7 +59,16 @@ where
         BufferUnordered {
             stream: super::Fuse::new(stream),
             in_progress_queue: FuturesUnordered::new(),
-            max: n,
+            max: Limit::Fixed(n),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub(super) fn with_limit(stream: St, limit: ConcurrencyLimit) -> BufferUnordered<St> {
+        BufferUnordered {
+            stream: super::Fuse::new(stream),
+            in_progress_queue: FuturesUnordered::new(),
+            max: Limit::Shared(limit),
         }
     }
 
@@ -106,7 +118,7 @@ where
     ) -> Poll<Option<Self::Item>> {
         // First up, try to spawn off as many futures as possible by filling up
         // our slab of futures.
-        while self.in_progress_queue.len() < self.max {
+        while self.max.has_capacity(self.in_progress_queue.len(), cx) {
             match self.as_mut().stream().poll_next(cx) {
                 Poll::Ready(Some(fut)) => self.as_mut().in_progress_queue().push(fut),
                 Poll::Ready(None) | Poll::Pending => break,
@@ -126,6 +138,17 @@ where
             Poll::Pending
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queue_len = self.in_progress_queue.len();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(queue_len);
+        let upper = match upper {
+            Some(x) => x.checked_add(queue_len),
+            None => None,
+        };
+        (lower, upper)
+    }
 }
 
 impl<St> FusedStream for BufferUnordered<St>
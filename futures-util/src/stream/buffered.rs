@@ -1,4 +1,7 @@
 use crate::stream::{Fuse, FuturesOrdered, StreamExt};
+use crate::stream::concurrency_limit::Limit;
+#[cfg(feature = "std")]
+use crate::stream::concurrency_limit::ConcurrencyLimit;
 use futures_core::future::Future;
 use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
@@ -16,7 +19,7 @@ where
 {
     stream: Fuse<St>,
     in_progress_queue: FuturesOrdered<St::Item>,
-    max: usize,
+    max: Limit,
 }
 
 impl<St> Unpin for Buffered<St>
@@ -51,7 +54,16 @@ where
         Buffered {
             stream: super::Fuse::new(stream),
             in_progress_queue: FuturesOrdered::new(),
-            max: n,
+            max: Limit::Fixed(n),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub(super) fn with_limit(stream: St, limit: ConcurrencyLimit) -> Buffered<St> {
+        Buffered {
+            stream: super::Fuse::new(stream),
+            in_progress_queue: FuturesOrdered::new(),
+            max: Limit::Shared(limit),
         }
     }
 
@@ -101,7 +113,7 @@ where
     ) -> Poll<Option<Self::Item>> {
         // Try to spawn off as many futures as possible by filling up
         // our in_progress_queue of futures.
-        while self.in_progress_queue.len() < self.max {
+        while self.max.has_capacity(self.in_progress_queue.len(), cx) {
             match self.as_mut().stream().poll_next(cx) {
                 Poll::Ready(Some(fut)) => self.as_mut().in_progress_queue().push(fut),
                 Poll::Ready(None) | Poll::Pending => break,
@@ -121,6 +133,17 @@ where
             Poll::Pending
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queue_len = self.in_progress_queue.len();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(queue_len);
+        let upper = match upper {
+            Some(x) => x.checked_add(queue_len),
+            None => None,
+        };
+        (lower, upper)
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
@@ -46,4 +46,12 @@ impl<St: Stream + UnwindSafe> Stream for CatchUnwind<St>
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.caught_unwind {
+            (0, Some(0))
+        } else {
+            self.stream.size_hint()
+        }
+    }
 }
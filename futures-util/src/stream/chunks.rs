@@ -104,6 +104,20 @@ impl<St: Stream> Stream for Chunks<St> {
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every `cap` items make a chunk, plus a final one for the rest.
+        let buffered = self.items.len();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(buffered).saturating_add(self.cap - 1) / self.cap;
+        let upper = match upper {
+            Some(x) => x.checked_add(buffered)
+                .and_then(|x| x.checked_add(self.cap - 1))
+                .map(|x| x / self.cap),
+            None => None,
+        };
+        (lower, upper)
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
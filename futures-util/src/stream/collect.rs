@@ -3,7 +3,6 @@ use core::pin::Pin;
 use futures_core::future::{FusedFuture, Future};
 use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Context, Poll};
-use pin_utils::{unsafe_pinned, unsafe_unpinned};
 
 /// Future for the [`collect`](super::StreamExt::collect) method.
 #[derive(Debug)]
@@ -16,13 +15,6 @@ pub struct Collect<St, C> {
 impl<St: Unpin + Stream, C> Unpin for Collect<St, C> {}
 
 impl<St: Stream, C: Default> Collect<St, C> {
-    unsafe_pinned!(stream: St);
-    unsafe_unpinned!(collection: C);
-
-    fn finish(mut self: Pin<&mut Self>) -> C {
-        mem::replace(self.as_mut().collection(), Default::default())
-    }
-
     pub(super) fn new(stream: St) -> Collect<St, C> {
         Collect {
             stream,
@@ -43,12 +35,60 @@ where St: Stream,
 {
     type Output = C;
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<C> {
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<C> {
+        let Collect { stream, collection } = unsafe { self.get_unchecked_mut() };
+        let stream = unsafe { Pin::new_unchecked(stream) };
+        let mut items = ReadyItems { stream, cx, state: None };
         loop {
-            match ready!(self.as_mut().stream().poll_next(cx)) {
-                Some(e) => self.as_mut().collection().extend(Some(e)),
-                None => return Poll::Ready(self.as_mut().finish()),
+            collection.extend(&mut items);
+            match items.state {
+                Some(Poll::Ready(())) => {
+                    return Poll::Ready(mem::replace(collection, Default::default()))
+                }
+                Some(Poll::Pending) => return Poll::Pending,
+                // The collection stopped early, so keep feeding it.
+                None => {}
+            }
+        }
+    }
+}
+
+// The items a stream yields without blocking, as an iterator. It passes the
+// stream's size hint on, so that the collection can reserve space for all of
+// the stream's items at once.
+struct ReadyItems<'a, 'b, St> {
+    stream: Pin<&'a mut St>,
+    cx: &'a mut Context<'b>,
+    // Set once the stream has ended or is pending.
+    state: Option<Poll<()>>,
+}
+
+impl<St: Stream> Iterator for ReadyItems<'_, '_, St> {
+    type Item = St::Item;
+
+    fn next(&mut self) -> Option<St::Item> {
+        if self.state.is_some() {
+            return None;
+        }
+        match self.stream.as_mut().poll_next(self.cx) {
+            Poll::Ready(Some(item)) => Some(item),
+            Poll::Ready(None) => {
+                self.state = Some(Poll::Ready(()));
+                None
             }
+            Poll::Pending => {
+                self.state = Some(Poll::Pending);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The iterator ends early if the stream is pending, which is fine as
+        // size hints are only ever trusted for optimizations.
+        match self.state {
+            Some(_) => (0, Some(0)),
+            None => self.stream.size_hint(),
         }
     }
 }
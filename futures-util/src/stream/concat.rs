@@ -1,3 +1,4 @@
+use core::mem;
 use core::pin::Pin;
 use futures_core::future::Future;
 use futures_core::stream::Stream;
@@ -40,20 +41,77 @@ where St: Stream,
     fn poll(
         mut self: Pin<&mut Self>, cx: &mut Context<'_>
     ) -> Poll<Self::Output> {
-        loop {
+        // The first item becomes the accumulator, keeping its allocation.
+        if self.accum.is_none() {
             match ready!(self.as_mut().stream().poll_next(cx)) {
-                None => {
-                    return Poll::Ready(self.as_mut().accum().take().unwrap_or_default())
+                None => return Poll::Ready(Default::default()),
+                Some(e) => *self.as_mut().accum() = Some(e),
+            }
+        }
+
+        let Concat { stream, accum } = unsafe { self.get_unchecked_mut() };
+        let stream = unsafe { Pin::new_unchecked(stream) };
+        let accum = accum.as_mut().unwrap();
+        let mut elements = ReadyElements { stream, cx, current: None, state: None };
+        loop {
+            accum.extend(&mut elements);
+            match elements.state {
+                Some(Poll::Ready(())) => {
+                    return Poll::Ready(mem::replace(accum, Default::default()))
                 }
-                Some(e) => {
-                    let accum = self.as_mut().accum();
-                    if let Some(a) = accum {
-                        a.extend(e)
-                    } else {
-                        *accum = Some(e)
-                    }
+                Some(Poll::Pending) => return Poll::Pending,
+                // The accumulator stopped early, so keep feeding it.
+                None => {}
+            }
+        }
+    }
+}
+
+// The elements of the items a stream yields without blocking, as an
+// iterator. It passes the size hints of the items on, so that the
+// accumulator can reserve space for each of them at once.
+struct ReadyElements<'a, 'b, St: Stream>
+    where St::Item: IntoIterator,
+{
+    stream: Pin<&'a mut St>,
+    cx: &'a mut Context<'b>,
+    current: Option<<St::Item as IntoIterator>::IntoIter>,
+    // Set once the stream has ended or is pending.
+    state: Option<Poll<()>>,
+}
+
+impl<St> Iterator for ReadyElements<'_, '_, St>
+    where St: Stream,
+          St::Item: IntoIterator,
+{
+    type Item = <St::Item as IntoIterator>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(current) = &mut self.current {
+                if let Some(element) = current.next() {
+                    return Some(element);
                 }
+                self.current = None;
+            }
+            if self.state.is_some() {
+                return None;
+            }
+            match self.stream.as_mut().poll_next(self.cx) {
+                Poll::Ready(Some(item)) => self.current = Some(item.into_iter()),
+                Poll::Ready(None) => self.state = Some(Poll::Ready(())),
+                Poll::Pending => self.state = Some(Poll::Pending),
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = match &self.current {
+            Some(current) => current.size_hint(),
+            None => (0, Some(0)),
+        };
+        // Later items may hold any number of elements, unless there are none.
+        let no_more_items = self.state.is_some() || self.stream.size_hint().1 == Some(0);
+        (lower, if no_more_items { upper } else { None })
+    }
 }
@@ -0,0 +1,272 @@
+//! Concurrency limits which can change at runtime
+
+use futures_core::task::Context;
+#[cfg(feature = "std")]
+use super::shared::Notifier;
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
+
+/// A concurrency limit which can be shared between combinators and changed
+/// while they are running.
+///
+/// The limit is passed to combinators like
+/// [`buffer_unordered_with_limit`](super::StreamExt::buffer_unordered_with_limit)
+/// and caps the number of futures they run at once. Clones of a
+/// `ConcurrencyLimit` refer to the same limit, so a clone can be kept to
+/// [`set`](ConcurrencyLimit::set) the limit from the outside, or be handed to
+/// an [`AimdController`] which adjusts it automatically.
+///
+/// Lowering the limit doesn't cancel any futures which are already running,
+/// the combinators just don't start new ones until enough of them finished.
+/// A limit of zero pauses them.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct ConcurrencyLimit {
+    inner: Arc<Inner>,
+}
+
+#[cfg(feature = "std")]
+struct Inner {
+    limit: AtomicUsize,
+    // The tasks waiting for the limit to be raised.
+    notifier: Notifier,
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for ConcurrencyLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcurrencyLimit")
+            .field("limit", &self.get())
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl ConcurrencyLimit {
+    /// Creates a new concurrency limit with the given initial value.
+    pub fn new(limit: usize) -> ConcurrencyLimit {
+        ConcurrencyLimit {
+            inner: Arc::new(Inner {
+                limit: AtomicUsize::new(limit),
+                notifier: Notifier::new(),
+            }),
+        }
+    }
+
+    /// Returns the current value of the limit.
+    pub fn get(&self) -> usize {
+        self.inner.limit.load(Ordering::SeqCst)
+    }
+
+    /// Sets the limit to a new value.
+    ///
+    /// If the limit is raised, the combinators using it are woken up so that
+    /// they can start more futures.
+    pub fn set(&self, limit: usize) {
+        let old = self.inner.limit.swap(limit, Ordering::SeqCst);
+        if limit > old {
+            self.inner.notifier.wake_all();
+        }
+    }
+
+    // Atomically replaces the limit with `f(limit)`.
+    fn update(&self, f: impl Fn(usize) -> usize) {
+        let mut old = self.get();
+        loop {
+            let new = f(old);
+            match self.inner.limit.compare_exchange(old, new, Ordering::SeqCst, Ordering::SeqCst) {
+                Ok(_) => {
+                    if new > old {
+                        self.inner.notifier.wake_all();
+                    }
+                    return;
+                }
+                Err(actual) => old = actual,
+            }
+        }
+    }
+}
+
+/// A controller which adjusts a [`ConcurrencyLimit`] with additive increase,
+/// multiplicative decrease (AIMD).
+///
+/// The controller is fed with the outcome of each operation running under
+/// the limit. For every `limit` successes, that is about once per round trip
+/// while the limit is fully used, the limit is raised by one, up to the
+/// maximum. An error, or a success which took longer than the
+/// [latency threshold](AimdController::latency_threshold), lowers the limit
+/// by the [backoff ratio](AimdController::backoff_ratio), down to the minimum.
+/// Operations which were started before the last decrease can't lower the
+/// limit again, so that a burst of failures caused by a single overload only
+/// backs off once. This way the limit converges to the concurrency the
+/// downstream service can handle, and quickly backs off once it gets
+/// overloaded.
+///
+/// Clones of a controller share their state and adjust the same limit.
+///
+/// # Examples
+///
+/// ```
+/// use futures::stream::{AimdController, ConcurrencyLimit};
+/// use std::time::Duration;
+///
+/// let limit = ConcurrencyLimit::new(10);
+/// let controller = AimdController::new(limit.clone(), 1, 100)
+///     .latency_threshold(Duration::from_millis(100));
+///
+/// for _ in 0..10 {
+///     controller.on_success(Duration::from_millis(20));
+/// }
+/// assert_eq!(limit.get(), 11);
+///
+/// controller.on_error(Duration::from_millis(20));
+/// assert_eq!(limit.get(), 5);
+///
+/// // This operation was already running when the limit was lowered.
+/// controller.on_error(Duration::from_millis(20));
+/// assert_eq!(limit.get(), 5);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct AimdController {
+    limit: ConcurrencyLimit,
+    min: usize,
+    max: usize,
+    backoff_ratio: f64,
+    latency_threshold: Option<Duration>,
+    state: Arc<Mutex<AimdState>>,
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct AimdState {
+    // Successes since the limit was last changed.
+    successes: usize,
+    last_decrease: Option<Instant>,
+}
+
+#[cfg(feature = "std")]
+impl AimdController {
+    /// Creates a controller which keeps `limit` between `min` and `max`.
+    ///
+    /// The current value of the limit is clamped to that range. The backoff
+    /// ratio defaults to one half, and no latency threshold is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is zero or greater than `max`.
+    pub fn new(limit: ConcurrencyLimit, min: usize, max: usize) -> AimdController {
+        assert!(min > 0, "the minimum concurrency limit must be positive");
+        assert!(min <= max, "the minimum concurrency limit exceeds the maximum");
+
+        limit.update(|limit| limit.max(min).min(max));
+        AimdController {
+            limit,
+            min,
+            max,
+            backoff_ratio: 0.5,
+            latency_threshold: None,
+            state: Arc::new(Mutex::new(AimdState {
+                successes: 0,
+                last_decrease: None,
+            })),
+        }
+    }
+
+    /// Sets the factor the limit is multiplied with when backing off.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` isn't between zero and one, exclusive.
+    pub fn backoff_ratio(mut self, ratio: f64) -> Self {
+        assert!(ratio > 0.0 && ratio < 1.0, "the backoff ratio must be between 0 and 1");
+        self.backoff_ratio = ratio;
+        self
+    }
+
+    /// Sets the latency above which a success is treated like an error.
+    pub fn latency_threshold(mut self, threshold: Duration) -> Self {
+        self.latency_threshold = Some(threshold);
+        self
+    }
+
+    /// Returns the limit adjusted by this controller.
+    pub fn limit(&self) -> &ConcurrencyLimit {
+        &self.limit
+    }
+
+    /// Records an operation which succeeded after the given latency.
+    pub fn on_success(&self, latency: Duration) {
+        match self.latency_threshold {
+            Some(threshold) if latency > threshold => self.back_off(latency),
+            _ => {
+                let mut state = self.state.lock().unwrap();
+                state.successes += 1;
+                if state.successes >= self.limit.get() {
+                    state.successes = 0;
+                    let max = self.max;
+                    self.limit.update(|limit| limit.saturating_add(1).min(max));
+                }
+            }
+        }
+    }
+
+    /// Records an operation which failed after the given latency.
+    ///
+    /// The latency tells the controller when the operation was started, so
+    /// that it can ignore failures of operations which were started before
+    /// the limit was last lowered.
+    pub fn on_error(&self, latency: Duration) {
+        self.back_off(latency);
+    }
+
+    fn back_off(&self, latency: Duration) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        if let Some(last_decrease) = state.last_decrease {
+            match now.checked_sub(latency) {
+                Some(started) if started >= last_decrease => {}
+                _ => return,
+            }
+        }
+        state.successes = 0;
+        state.last_decrease = Some(now);
+        let (min, ratio) = (self.min, self.backoff_ratio);
+        self.limit.update(|limit| ((limit as f64 * ratio) as usize).max(min));
+    }
+}
+
+// The concurrency limit of a combinator, which is either fixed or shared.
+#[derive(Debug)]
+pub(super) enum Limit {
+    Fixed(usize),
+    #[cfg(feature = "std")]
+    Shared(ConcurrencyLimit),
+}
+
+impl Limit {
+    // Returns whether another future may be started while `len` are running.
+    // If not, the task is woken once the limit is raised.
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    pub(super) fn has_capacity(&self, len: usize, cx: &mut Context<'_>) -> bool {
+        match self {
+            Limit::Fixed(limit) => len < *limit,
+            #[cfg(feature = "std")]
+            Limit::Shared(limit) => {
+                if len < limit.get() {
+                    return true;
+                }
+                limit.inner.notifier.register(cx.waker());
+                // The limit could have been raised before the task was
+                // registered.
+                len < limit.get()
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`count`](super::StreamExt::count) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Count<St> {
+    stream: St,
+    count: usize,
+}
+
+impl<St: Unpin> Unpin for Count<St> {}
+
+impl<St: Stream> Count<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(count: usize);
+
+    pub(super) fn new(stream: St) -> Count<St> {
+        Count { stream, count: 0 }
+    }
+}
+
+impl<St: FusedStream> FusedFuture for Count<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St: Stream> Future for Count<St> {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+        while ready!(self.as_mut().stream().poll_next(cx)).is_some() {
+            *self.as_mut().count() += 1;
+        }
+        Poll::Ready(self.count)
+    }
+}
@@ -0,0 +1,83 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`cycle`](super::StreamExt::cycle) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Cycle<St> {
+    // A copy of the stream as it was at the start, which is never polled.
+    orig: St,
+    stream: St,
+    // Whether the current run of the stream has yielded an item.
+    yielded: bool,
+    done: bool,
+}
+
+impl<St: Unpin> Unpin for Cycle<St> {}
+
+impl<St: Stream + Clone> Cycle<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(yielded: bool);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St) -> Cycle<St> {
+        Cycle {
+            orig: stream.clone(),
+            stream,
+            yielded: false,
+            done: false,
+        }
+    }
+}
+
+impl<St: Stream + Clone> Stream for Cycle<St> {
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => {
+                    *self.as_mut().yielded() = true;
+                    return Poll::Ready(Some(item));
+                }
+                // An empty stream would be restarted forever.
+                None if !self.yielded => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(None);
+                }
+                None => {
+                    let stream = self.orig.clone();
+                    self.as_mut().stream().set(stream);
+                    *self.as_mut().yielded() = false;
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        match self.orig.size_hint() {
+            (0, Some(0)) => self.stream.size_hint(),
+            (0, _) => (self.stream.size_hint().0, None),
+            _ => (usize::max_value(), None),
+        }
+    }
+}
+
+impl<St: Stream + Clone> FusedStream for Cycle<St> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
@@ -0,0 +1,128 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`dedup_by_key`](super::StreamExt::dedup_by_key) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct DedupByKey<St, K, F> {
+    stream: St,
+    f: F,
+    last_key: Option<K>,
+}
+
+impl<St: Unpin, K, F> Unpin for DedupByKey<St, K, F> {}
+
+impl<St, K, F> fmt::Debug for DedupByKey<St, K, F>
+where
+    St: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DedupByKey")
+            .field("stream", &self.stream)
+            .field("last_key", &self.last_key)
+            .finish()
+    }
+}
+
+impl<St, K, F> DedupByKey<St, K, F>
+    where St: Stream,
+          K: PartialEq,
+          F: FnMut(&St::Item) -> K,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(last_key: Option<K>);
+
+    pub(super) fn new(stream: St, f: F) -> DedupByKey<St, K, F> {
+        DedupByKey { stream, f, last_key: None }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut St> {
+        self.stream()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, K, F> FusedStream for DedupByKey<St, K, F>
+    where St: Stream + FusedStream,
+          K: PartialEq,
+          F: FnMut(&St::Item) -> K,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, K, F> Stream for DedupByKey<St, K, F>
+    where St: Stream,
+          K: PartialEq,
+          F: FnMut(&St::Item) -> K,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St::Item>> {
+        loop {
+            let item = match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => item,
+                None => return Poll::Ready(None),
+            };
+            let key = (self.as_mut().f())(&item);
+            if self.last_key.as_ref() != Some(&key) {
+                *self.as_mut().last_key() = Some(key);
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // All further items could have the same key as the first one.
+        let (lower, upper) = self.stream.size_hint();
+        let lower = if self.last_key.is_none() { lower.min(1) } else { 0 };
+        (lower, upper)
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, K, F, Item> Sink<Item> for DedupByKey<S, K, F>
+    where S: Stream + Sink<Item>,
+          K: PartialEq,
+          F: FnMut(&S::Item) -> K,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
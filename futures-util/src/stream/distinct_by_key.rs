@@ -0,0 +1,175 @@
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::Hash;
+use std::pin::Pin;
+
+/// Stream for the [`distinct_by_key`](super::StreamExt::distinct_by_key)
+/// method.
+#[must_use = "streams do nothing unless polled"]
+pub struct DistinctByKey<St, K, F> {
+    stream: St,
+    f: F,
+    seen: Seen<K>,
+}
+
+// A set of the `capacity` most recently seen keys.
+#[derive(Debug)]
+struct Seen<K> {
+    // Maps every key to the time it was seen last.
+    keys: HashMap<K, u64>,
+    // Maps those times back to the keys, oldest first.
+    by_time: BTreeMap<u64, K>,
+    time: u64,
+    capacity: usize,
+}
+
+impl<K: Eq + Hash + Clone> Seen<K> {
+    // Marks `key` as seen, returning whether it had been seen before.
+    fn insert(&mut self, key: K) -> bool {
+        let time = self.time;
+        self.time += 1;
+
+        if let Some(last_seen) = self.keys.get_mut(&key) {
+            self.by_time.remove(last_seen);
+            *last_seen = time;
+            self.by_time.insert(time, key);
+            return true;
+        }
+
+        if self.keys.len() == self.capacity {
+            let oldest = *self.by_time.keys().next().unwrap();
+            let evicted = self.by_time.remove(&oldest).unwrap();
+            self.keys.remove(&evicted);
+        }
+        self.keys.insert(key.clone(), time);
+        self.by_time.insert(time, key);
+        false
+    }
+}
+
+impl<St: Unpin, K, F> Unpin for DistinctByKey<St, K, F> {}
+
+impl<St, K, F> fmt::Debug for DistinctByKey<St, K, F>
+where
+    St: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DistinctByKey")
+            .field("stream", &self.stream)
+            .field("seen", &self.seen)
+            .finish()
+    }
+}
+
+impl<St, K, F> DistinctByKey<St, K, F>
+    where St: Stream,
+          K: Eq + Hash + Clone,
+          F: FnMut(&St::Item) -> K,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(seen: Seen<K>);
+
+    pub(super) fn new(stream: St, capacity: usize, f: F) -> DistinctByKey<St, K, F> {
+        assert!(capacity > 0);
+
+        DistinctByKey {
+            stream,
+            f,
+            seen: Seen {
+                keys: HashMap::with_capacity(capacity),
+                by_time: BTreeMap::new(),
+                time: 0,
+                capacity,
+            },
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut St> {
+        self.stream()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, K, F> FusedStream for DistinctByKey<St, K, F>
+    where St: Stream + FusedStream,
+          K: Eq + Hash + Clone,
+          F: FnMut(&St::Item) -> K,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, K, F> Stream for DistinctByKey<St, K, F>
+    where St: Stream,
+          K: Eq + Hash + Clone,
+          F: FnMut(&St::Item) -> K,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St::Item>> {
+        loop {
+            let item = match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => item,
+                None => return Poll::Ready(None),
+            };
+            let key = (self.as_mut().f())(&item);
+            if !self.as_mut().seen().insert(key) {
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let lower = if self.seen.keys.is_empty() { lower.min(1) } else { 0 };
+        (lower, upper)
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, K, F, Item> Sink<Item> for DistinctByKey<S, K, F>
+    where S: Stream + Sink<Item>,
+          K: Eq + Hash + Clone,
+          F: FnMut(&S::Item) -> K,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,104 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`find`](super::StreamExt::find) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Find<St, Fut, F>
+    where St: Stream,
+{
+    stream: St,
+    f: F,
+    pending_fut: Option<Fut>,
+    pending_item: Option<St::Item>,
+    done: bool,
+}
+
+impl<St, Fut, F> Unpin for Find<St, Fut, F>
+where
+    St: Stream + Unpin,
+    Fut: Unpin,
+{}
+
+impl<St, Fut, F> fmt::Debug for Find<St, Fut, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Find")
+            .field("stream", &self.stream)
+            .field("pending_fut", &self.pending_fut)
+            .field("pending_item", &self.pending_item)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> Find<St, Fut, F>
+    where St: Stream,
+          F: FnMut(&St::Item) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(pending_fut: Option<Fut>);
+    unsafe_unpinned!(pending_item: Option<St::Item>);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, f: F) -> Find<St, Fut, F> {
+        Find {
+            stream,
+            f,
+            pending_fut: None,
+            pending_item: None,
+            done: false,
+        }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for Find<St, Fut, F>
+    where St: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St, Fut, F> Future for Find<St, Fut, F>
+    where St: Stream,
+          F: FnMut(&St::Item) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    type Output = Option<St::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if self.pending_fut.is_none() {
+                let item = match ready!(self.as_mut().stream().poll_next(cx)) {
+                    Some(e) => e,
+                    None => {
+                        *self.as_mut().done() = true;
+                        return Poll::Ready(None);
+                    }
+                };
+                let fut = (self.as_mut().f())(&item);
+                self.as_mut().pending_fut().set(Some(fut));
+                *self.as_mut().pending_item() = Some(item);
+            }
+
+            let found = ready!(self.as_mut().pending_fut().as_pin_mut().unwrap().poll(cx));
+            self.as_mut().pending_fut().set(None);
+            let item = self.as_mut().pending_item().take().unwrap();
+
+            if found {
+                *self.as_mut().done() = true;
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+}
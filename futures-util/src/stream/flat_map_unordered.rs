@@ -0,0 +1,121 @@
+use crate::stream::{FlattenUnordered, Map, StreamExt};
+use core::fmt;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::unsafe_pinned;
+
+/// Stream for the [`flat_map_unordered`](super::StreamExt::flat_map_unordered)
+/// method.
+#[must_use = "streams do nothing unless polled"]
+pub struct FlatMapUnordered<St, U, F>
+    where St: Stream,
+          U: Stream + Unpin,
+          F: FnMut(St::Item) -> U,
+{
+    inner: FlattenUnordered<Map<St, F>>,
+}
+
+impl<St, U, F> Unpin for FlatMapUnordered<St, U, F>
+    where St: Stream + Unpin,
+          U: Stream + Unpin,
+          F: FnMut(St::Item) -> U,
+{}
+
+impl<St, U, F> fmt::Debug for FlatMapUnordered<St, U, F>
+where
+    St: Stream + fmt::Debug,
+    U: Stream + Unpin + fmt::Debug,
+    F: FnMut(St::Item) -> U,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlatMapUnordered")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<St, U, F> FlatMapUnordered<St, U, F>
+    where St: Stream,
+          U: Stream + Unpin,
+          F: FnMut(St::Item) -> U,
+{
+    unsafe_pinned!(inner: FlattenUnordered<Map<St, F>>);
+
+    pub(super) fn new(stream: St, limit: usize, f: F) -> FlatMapUnordered<St, U, F> {
+        FlatMapUnordered { inner: stream.map(f).flatten_unordered(limit) }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.inner.get_ref().get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.inner.get_mut().get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut St> {
+        self.inner().get_pin_mut().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.inner.into_inner().into_inner()
+    }
+}
+
+impl<St, U, F> FusedStream for FlatMapUnordered<St, U, F>
+    where St: Stream,
+          U: Stream + Unpin,
+          F: FnMut(St::Item) -> U,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<St, U, F> Stream for FlatMapUnordered<St, U, F>
+    where St: Stream,
+          U: Stream + Unpin,
+          F: FnMut(St::Item) -> U,
+{
+    type Item = U::Item;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<U::Item>> {
+        self.inner().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<St, U, F, Item> Sink<Item> for FlatMapUnordered<St, U, F>
+    where St: Stream + Sink<Item>,
+          U: Stream + Unpin,
+          F: FnMut(St::Item) -> U,
+{
+    type SinkError = St::SinkError;
+
+    delegate_sink!(inner, Item);
+}
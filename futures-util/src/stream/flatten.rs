@@ -94,6 +94,18 @@ impl<St> Stream for Flatten<St>
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = match &self.next {
+            Some(next) => next.size_hint(),
+            None => (0, Some(0)),
+        };
+        // Any further inner stream could yield any number of items.
+        match self.stream.size_hint() {
+            (_, Some(0)) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
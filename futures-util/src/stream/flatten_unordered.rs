@@ -0,0 +1,165 @@
+use crate::stream::{Fuse, FuturesUnordered, StreamExt, StreamFuture};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use core::fmt;
+use core::pin::Pin;
+
+/// Stream for the [`flatten_unordered`](super::StreamExt::flatten_unordered)
+/// method.
+#[must_use = "streams do nothing unless polled"]
+pub struct FlattenUnordered<St>
+where
+    St: Stream,
+    St::Item: Stream + Unpin,
+{
+    stream: Fuse<St>,
+    inner_streams: FuturesUnordered<StreamFuture<St::Item>>,
+    limit: usize,
+}
+
+impl<St> Unpin for FlattenUnordered<St>
+where
+    St: Stream + Unpin,
+    St::Item: Stream + Unpin,
+{}
+
+impl<St> fmt::Debug for FlattenUnordered<St>
+where
+    St: Stream + fmt::Debug,
+    St::Item: Stream + Unpin + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlattenUnordered")
+            .field("stream", &self.stream)
+            .field("inner_streams", &self.inner_streams)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<St> FlattenUnordered<St>
+where
+    St: Stream,
+    St::Item: Stream + Unpin,
+{
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(inner_streams: FuturesUnordered<StreamFuture<St::Item>>);
+
+    pub(super) fn new(stream: St, limit: usize) -> FlattenUnordered<St> {
+        assert!(limit > 0);
+
+        FlattenUnordered {
+            stream: super::Fuse::new(stream),
+            inner_streams: FuturesUnordered::new(),
+            limit,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut St> {
+        self.stream().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St> Stream for FlattenUnordered<St>
+where
+    St: Stream,
+    St::Item: Stream + Unpin,
+{
+    type Item = <St::Item as Stream>::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            // Pull in as many inner streams as the limit allows.
+            while self.inner_streams.len() < self.limit {
+                match self.as_mut().stream().poll_next(cx) {
+                    Poll::Ready(Some(stream)) => {
+                        self.as_mut().inner_streams().push(stream.into_future())
+                    }
+                    Poll::Ready(None) | Poll::Pending => break,
+                }
+            }
+
+            match self.as_mut().inner_streams().poll_next_unpin(cx) {
+                Poll::Ready(Some((Some(item), rest))) => {
+                    self.as_mut().inner_streams().push(rest.into_future());
+                    return Poll::Ready(Some(item));
+                }
+                // An inner stream has finished, which makes room for the
+                // next one.
+                Poll::Ready(Some((None, _))) => {}
+                Poll::Ready(None) => {
+                    // If more streams are still coming, we're not done yet
+                    return if self.stream.is_done() {
+                        Poll::Ready(None)
+                    } else {
+                        Poll::Pending
+                    };
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Any inner stream could yield any number of items.
+        if self.inner_streams.is_empty() && self.stream.size_hint().1 == Some(0) {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+impl<St> FusedStream for FlattenUnordered<St>
+where
+    St: Stream,
+    St::Item: Stream + Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner_streams.is_terminated() && self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Item> Sink<Item> for FlattenUnordered<S>
+where
+    S: Stream + Sink<Item>,
+    S::Item: Stream + Unpin,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
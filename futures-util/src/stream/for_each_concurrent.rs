@@ -1,7 +1,9 @@
 use crate::stream::{FuturesUnordered, StreamExt};
+use crate::stream::concurrency_limit::Limit;
+#[cfg(feature = "std")]
+use crate::stream::concurrency_limit::ConcurrencyLimit;
 use core::fmt;
 use core::pin::Pin;
-use core::num::NonZeroUsize;
 use futures_core::future::{FusedFuture, Future};
 use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
@@ -14,7 +16,8 @@ pub struct ForEachConcurrent<St, Fut, F> {
     stream: Option<St>,
     f: F,
     futures: FuturesUnordered<Fut>,
-    limit: Option<NonZeroUsize>,
+    // `None` if there is no limit.
+    limit: Option<Limit>,
 }
 
 impl<St, Fut, F> Unpin for ForEachConcurrent<St, Fut, F>
@@ -44,13 +47,26 @@ where St: Stream,
     unsafe_pinned!(stream: Option<St>);
     unsafe_unpinned!(f: F);
     unsafe_unpinned!(futures: FuturesUnordered<Fut>);
-    unsafe_unpinned!(limit: Option<NonZeroUsize>);
 
     pub(super) fn new(stream: St, limit: Option<usize>, f: F) -> ForEachConcurrent<St, Fut, F> {
         ForEachConcurrent {
             stream: Some(stream),
             // Note: `limit` = 0 gets ignored.
-            limit: limit.and_then(NonZeroUsize::new),
+            limit: limit.filter(|&limit| limit > 0).map(Limit::Fixed),
+            f,
+            futures: FuturesUnordered::new(),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub(super) fn with_limit(
+        stream: St,
+        limit: ConcurrencyLimit,
+        f: F,
+    ) -> ForEachConcurrent<St, Fut, F> {
+        ForEachConcurrent {
+            stream: Some(stream),
+            limit: Some(Limit::Shared(limit)),
             f,
             futures: FuturesUnordered::new(),
         }
@@ -77,7 +93,11 @@ impl<St, Fut, F> Future for ForEachConcurrent<St, Fut, F>
             // Try and pull an item from the stream
             let current_len = self.futures.len();
             // Check if we've already created a number of futures greater than `limit`
-            if self.limit.map(|limit| limit.get() > current_len).unwrap_or(true) {
+            let has_capacity = match &self.limit {
+                Some(limit) => limit.has_capacity(current_len, cx),
+                None => true,
+            };
+            if has_capacity {
                 let mut stream_completed = false;
                 let elem = if let Some(stream) = self.as_mut().stream().as_pin_mut() {
                     match stream.poll_next(cx) {
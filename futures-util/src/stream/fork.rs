@@ -0,0 +1,72 @@
+//! Shared logic of the streams which split one stream into two halves.
+
+use super::shared::Notifier;
+use crate::task::waker_ref;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Polls one half of a split stream.
+///
+/// Items are taken from the buffer of this half first. Once it is empty, the
+/// underlying stream is polled, and `split` divides each of its items between
+/// this half and the other one, whose buffer is `None` once it has been
+/// dropped. The other half's buffer holds at most `capacity` items; when it
+/// is full, this half waits for the other one to catch up.
+pub(super) fn poll_half<St, T, U, F>(
+    stream: &mut Option<Pin<Box<St>>>,
+    own: &mut VecDeque<T>,
+    mut other: Option<&mut VecDeque<U>>,
+    capacity: usize,
+    notifier: &Arc<Notifier>,
+    cx: &mut Context<'_>,
+    mut split: F,
+) -> Poll<Option<T>>
+    where St: Stream,
+          F: FnMut(St::Item) -> (Option<T>, Option<U>),
+{
+    loop {
+        if let Some(item) = own.pop_front() {
+            // The other half may be waiting for space.
+            notifier.wake_all();
+            return Poll::Ready(Some(item));
+        }
+
+        let inner = match stream {
+            Some(inner) => inner,
+            None => return Poll::Ready(None),
+        };
+
+        // Register before checking for space or polling the stream, so that
+        // no wakeup is missed.
+        notifier.register(cx.waker());
+        if let Some(other) = &other {
+            if other.len() >= capacity {
+                return Poll::Pending;
+            }
+        }
+
+        let waker = waker_ref(notifier);
+        let mut stream_cx = Context::from_waker(&waker);
+        match inner.as_mut().poll_next(&mut stream_cx) {
+            Poll::Ready(Some(item)) => {
+                let (own_item, other_item) = split(item);
+                if let (Some(other), Some(item)) = (&mut other, other_item) {
+                    other.push_back(item);
+                    notifier.wake_all();
+                }
+                if let Some(item) = own_item {
+                    return Poll::Ready(Some(item));
+                }
+            }
+            Poll::Ready(None) => {
+                *stream = None;
+                notifier.wake_all();
+                return Poll::Ready(None);
+            }
+            Poll::Pending => return Poll::Pending,
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use crate::stream::futures_unordered::TaskHandle;
+use crate::stream::{FuturesUnordered, StreamExt};
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::pin::Pin;
+
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+struct KeyWrapper<K, Fut> {
+    key: Option<K>, // `None` once the future has completed
+    future: Fut,
+}
+
+impl<K, Fut> KeyWrapper<K, Fut> {
+    unsafe_unpinned!(key: Option<K>);
+    unsafe_pinned!(future: Fut);
+}
+
+impl<K, Fut> Future for KeyWrapper<K, Fut>
+    where Fut: Future
+{
+    type Output = (K, Fut::Output);
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        let output = ready!(self.as_mut().future().poll(cx));
+        let key = self.key().take().expect("KeyWrapper polled after completion");
+        Poll::Ready((key, output))
+    }
+}
+
+/// A set of futures, each identified by a key, which completes them in
+/// whichever order they finish.
+///
+/// Like [`FuturesUnordered`](super::FuturesUnordered), which it is built on,
+/// `FuturesMap` only polls the futures which have been woken. In addition,
+/// every future is inserted under a key, which is yielded together with its
+/// output, and a future can be cancelled or replaced through its key. This
+/// makes it possible to track many requests from a single task, and to time
+/// them out or abort them individually.
+///
+/// Once the map is empty, it yields `None`, but it can be reused by
+/// inserting more futures.
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, FutureExt};
+/// use futures::stream::{FuturesMap, StreamExt};
+///
+/// let mut map = FuturesMap::new();
+/// map.insert("a", future::ready(1).boxed());
+/// map.insert("b", future::pending().boxed());
+/// map.insert("c", future::ready(3).boxed());
+///
+/// // Drops the pending future.
+/// assert!(map.cancel(&"b"));
+///
+/// let mut outputs = map.collect::<Vec<_>>().await;
+/// outputs.sort();
+/// assert_eq!(outputs, vec![("a", 1), ("c", 3)]);
+/// # });
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct FuturesMap<K, Fut> {
+    futures: FuturesUnordered<KeyWrapper<K, Fut>>,
+    handles: HashMap<K, TaskHandle<KeyWrapper<K, Fut>>>,
+}
+
+impl<K, Fut> Unpin for FuturesMap<K, Fut> {}
+
+impl<K: Debug, Fut> Debug for FuturesMap<K, Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FuturesMap")
+            .field("keys", &self.handles.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<K, Fut> FuturesMap<K, Fut>
+    where K: Clone + Eq + Hash,
+          Fut: Future,
+{
+    /// Constructs a new, empty `FuturesMap`.
+    ///
+    /// The returned `FuturesMap` does not contain any futures and, in this
+    /// state, `FuturesMap::poll_next` will return `Poll::Ready(None)`.
+    pub fn new() -> FuturesMap<K, Fut> {
+        FuturesMap {
+            futures: FuturesUnordered::new(),
+            handles: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of futures contained in the map.
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Returns `true` if the map contains no futures.
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Inserts a future into the map under the given key.
+    ///
+    /// If the map already contained a future under this key, that future is
+    /// dropped and replaced, and `true` is returned. Its output will never
+    /// be yielded.
+    ///
+    /// This function will not call `poll` on the submitted future. The caller
+    /// must ensure that `FuturesMap::poll_next` is called in order to receive
+    /// task notifications.
+    pub fn insert(&mut self, key: K, future: Fut) -> bool {
+        let replaced = self.cancel(&key);
+        let handle = self.futures.push_with_handle(KeyWrapper {
+            key: Some(key.clone()),
+            future,
+        });
+        self.handles.insert(key, handle);
+        replaced
+    }
+
+    /// Drops the future with the given key.
+    ///
+    /// Returns `true` if the map contained a future under this key. Its
+    /// output will never be yielded.
+    pub fn cancel(&mut self, key: &K) -> bool {
+        match self.handles.remove(key) {
+            Some(handle) => self.futures.remove(&handle),
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the map contains a future with the given key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.handles.contains_key(key)
+    }
+
+    /// Returns an iterator over the keys of the futures in the map, in no
+    /// particular order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.handles.keys()
+    }
+}
+
+impl<K, Fut> Default for FuturesMap<K, Fut>
+    where K: Clone + Eq + Hash,
+          Fut: Future,
+{
+    fn default() -> FuturesMap<K, Fut> {
+        FuturesMap::new()
+    }
+}
+
+impl<K, Fut> Stream for FuturesMap<K, Fut>
+    where K: Clone + Eq + Hash,
+          Fut: Future,
+{
+    type Item = (K, Fut::Output);
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        match ready!(self.futures.poll_next_unpin(cx)) {
+            Some((key, output)) => {
+                self.handles.remove(&key);
+                Poll::Ready(Some((key, output)))
+            }
+            None => Poll::Ready(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<K, Fut> FusedStream for FuturesMap<K, Fut>
+    where K: Clone + Eq + Hash,
+          Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.futures.is_terminated()
+    }
+}
+
+impl<K, Fut> FromIterator<(K, Fut)> for FuturesMap<K, Fut>
+    where K: Clone + Eq + Hash,
+          Fut: Future,
+{
+    fn from_iter<T: IntoIterator<Item = (K, Fut)>>(iter: T) -> Self {
+        let mut map = FuturesMap::new();
+        for (key, future) in iter {
+            map.insert(key, future);
+        }
+        map
+    }
+}
@@ -7,6 +7,8 @@ use crate::task::{AtomicWaker};
 use futures_core::future::{Future, FutureObj, LocalFutureObj};
 use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Context, Poll, Spawn, LocalSpawn, SpawnError};
+#[cfg(feature = "std")]
+use futures_core::task::poll_budget;
 use core::cell::UnsafeCell;
 use core::fmt::{self, Debug};
 use core::iter::FromIterator;
@@ -21,7 +23,7 @@ use alloc::sync::{Arc, Weak};
 mod abort;
 
 mod iter;
-pub use self::iter::{IterMut, IterPinMut};
+pub use self::iter::{Iter, IterMut, IterPinMut, IterPinRef};
 
 mod task;
 use self::task::Task;
@@ -167,6 +169,12 @@ impl<Fut> FuturesUnordered<Fut> {
     /// ensure that [`FuturesUnordered::poll_next`](Stream::poll_next) is called
     /// in order to receive wake-up notifications for the given future.
     pub fn push(&mut self, future: Fut) {
+        self.push_with_handle(future);
+    }
+
+    /// Push a future into the set, returning a handle with which it can be
+    /// removed again.
+    pub(crate) fn push_with_handle(&mut self, future: Fut) -> TaskHandle<Fut> {
         let task = Arc::new(Task {
             future: UnsafeCell::new(Some(future)),
             next_all: UnsafeCell::new(ptr::null_mut()),
@@ -182,6 +190,8 @@ impl<Fut> FuturesUnordered<Fut> {
             self.len = 0;
         }
 
+        let handle = TaskHandle(Arc::downgrade(&task));
+
         // Right now our task has a strong reference count of 1. We transfer
         // ownership of this reference count to our internal linked list
         // and we'll reclaim ownership through the `unlink` method below.
@@ -192,6 +202,53 @@ impl<Fut> FuturesUnordered<Fut> {
         // futures are ready. To do that we unconditionally enqueue it for
         // polling here.
         self.ready_to_run_queue.enqueue(ptr);
+
+        handle
+    }
+
+    /// Removes the future with the given handle from the set and drops it.
+    ///
+    /// Returns `false` if the future already completed or was removed.
+    pub(crate) fn remove(&mut self, handle: &TaskHandle<Fut>) -> bool {
+        let task = match handle.0.upgrade() {
+            Some(task) => task,
+            None => return false,
+        };
+
+        // Only tasks which are still linked have a future, as `release_task`
+        // drops it. Tasks are only unlinked without being released while
+        // they are polled, which can't happen concurrently as we have
+        // `&mut self`.
+        //
+        // Safety: We are the only thread that accesses the `UnsafeCell` that
+        // contains the future
+        if unsafe { (*task.future.get()).is_none() } {
+            return false;
+        }
+
+        // The linked list still holds a reference count, so the pointer stays
+        // valid.
+        let ptr = &*task as *const Task<Fut>;
+        drop(task);
+        unsafe {
+            let task = self.unlink(ptr);
+            self.release_task(task);
+        }
+        true
+    }
+
+    /// Returns an iterator that allows inspecting each future in the set.
+    pub fn iter(&self) -> Iter<'_, Fut> where Fut: Unpin {
+        Iter(Pin::new(self).iter_pin_ref())
+    }
+
+    /// Returns an iterator that allows inspecting each future in the set.
+    pub fn iter_pin_ref<'a>(self: Pin<&'a Self>) -> IterPinRef<'a, Fut> {
+        IterPinRef {
+            task: self.head_all,
+            len: self.len(),
+            _marker: PhantomData
+        }
     }
 
     /// Returns an iterator that allows modifying each future in the set.
@@ -298,6 +355,14 @@ impl<Fut: Future> Stream for FuturesUnordered<Fut> {
         self.ready_to_run_queue.waker.register(cx.waker());
 
         loop {
+            // Yield to the executor if this task has used up its budget.
+            #[cfg(feature = "std")]
+            {
+                if poll_budget(cx).is_pending() {
+                    return Poll::Pending;
+                }
+            }
+
             // Safety: &mut self guarantees the mutual exclusion `dequeue`
             // expects
             let task = match unsafe { self.ready_to_run_queue.dequeue() } {
@@ -427,8 +492,17 @@ impl<Fut: Future> Stream for FuturesUnordered<Fut> {
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
+/// Identifies a future pushed into a [`FuturesUnordered`] with
+/// `push_with_handle`.
+pub(crate) struct TaskHandle<Fut>(Weak<Task<Fut>>);
+
 impl<Fut> Debug for FuturesUnordered<Fut> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "FuturesUnordered {{ ... }}")
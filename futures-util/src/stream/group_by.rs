@@ -0,0 +1,220 @@
+use super::shared::Notifier;
+use crate::task::waker_ref;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// Stream for the [`group_by`](super::StreamExt::group_by) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct GroupBy<St: Stream, K, F> {
+    inner: Arc<Inner<St, K, F>>,
+}
+
+/// A run of items with equal keys, yielded by [`GroupBy`].
+#[must_use = "streams do nothing unless polled"]
+pub struct Group<St: Stream, K, F> {
+    inner: Arc<Inner<St, K, F>>,
+    id: usize,
+}
+
+struct Inner<St: Stream, K, F> {
+    state: Mutex<State<St, K, F>>,
+    notifier: Arc<Notifier>,
+}
+
+struct State<St: Stream, K, F> {
+    // `None` once the stream has ended.
+    stream: Option<Pin<Box<St>>>,
+    f: F,
+    // The buffers of the groups from `first_id` on, `None` for the groups
+    // which have been dropped. The last group which has been yielded is the
+    // one the stream is currently in.
+    groups: VecDeque<Option<VecDeque<St::Item>>>,
+    first_id: usize,
+    next_id: usize,
+    // The key of the current group.
+    key: Option<K>,
+    // The first item of the next group, which has been pulled from the stream
+    // but not yielded yet.
+    next: Option<(K, St::Item)>,
+    capacity: usize,
+}
+
+// The stream itself is polled behind the `Arc`, so it won't be moved
+// when `GroupBy` or `Group` are moved.
+impl<St: Stream, K, F> Unpin for GroupBy<St, K, F> {}
+impl<St: Stream, K, F> Unpin for Group<St, K, F> {}
+
+impl<St: Stream, K, F> fmt::Debug for GroupBy<St, K, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GroupBy").finish()
+    }
+}
+
+impl<St: Stream, K, F> fmt::Debug for Group<St, K, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Group")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<St, K, F> GroupBy<St, K, F>
+    where St: Stream,
+          K: PartialEq + Clone,
+          F: FnMut(&St::Item) -> K,
+{
+    pub(super) fn new(stream: St, capacity: usize, f: F) -> GroupBy<St, K, F> {
+        assert!(capacity > 0);
+
+        let state = State {
+            stream: Some(Box::pin(stream)),
+            f,
+            groups: VecDeque::new(),
+            first_id: 0,
+            next_id: 0,
+            key: None,
+            next: None,
+            capacity,
+        };
+        GroupBy {
+            inner: Arc::new(Inner {
+                state: Mutex::new(state),
+                notifier: Arc::new(Notifier::new()),
+            }),
+        }
+    }
+}
+
+impl<St, K, F> State<St, K, F>
+    where St: Stream,
+          K: PartialEq,
+          F: FnMut(&St::Item) -> K,
+{
+    // The buffer of a group, or `None` if it has been dropped.
+    fn buffer(&mut self, id: usize) -> Option<&mut VecDeque<St::Item>> {
+        // Dropped groups at the front have been removed altogether.
+        let idx = id.checked_sub(self.first_id)?;
+        self.groups.get_mut(idx)?.as_mut()
+    }
+
+    // Pulls an item from the stream into the current group, or into `next` if
+    // it starts a new group.
+    fn pull(&mut self, notifier: &Arc<Notifier>) -> Poll<()> {
+        let stream = match &mut self.stream {
+            Some(stream) => stream,
+            None => return Poll::Ready(()),
+        };
+
+        let waker = waker_ref(notifier);
+        let mut stream_cx = Context::from_waker(&waker);
+        match stream.as_mut().poll_next(&mut stream_cx) {
+            Poll::Ready(Some(item)) => {
+                let key = (self.f)(&item);
+                if self.key.as_ref() == Some(&key) {
+                    let current = self.next_id - 1;
+                    // The items of a dropped group are discarded.
+                    if let Some(buffer) = self.buffer(current) {
+                        buffer.push_back(item);
+                    }
+                } else {
+                    self.next = Some((key, item));
+                }
+            }
+            Poll::Ready(None) => self.stream = None,
+            Poll::Pending => return Poll::Pending,
+        }
+        notifier.wake_all();
+        Poll::Ready(())
+    }
+}
+
+impl<St, K, F> Stream for GroupBy<St, K, F>
+    where St: Stream,
+          K: PartialEq + Clone,
+          F: FnMut(&St::Item) -> K,
+{
+    type Item = (K, Group<St, K, F>);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let inner = &self.inner;
+        let mut state = inner.state.lock().unwrap();
+        loop {
+            if let Some((key, item)) = state.next.take() {
+                let id = state.next_id;
+                state.next_id += 1;
+                state.key = Some(key.clone());
+                let mut buffer = VecDeque::new();
+                buffer.push_back(item);
+                state.groups.push_back(Some(buffer));
+                let group = Group { inner: inner.clone(), id };
+                return Poll::Ready(Some((key, group)));
+            }
+
+            if state.stream.is_none() {
+                return Poll::Ready(None);
+            }
+
+            // Register before checking for space or polling the stream, so
+            // that no wakeup is missed.
+            inner.notifier.register(cx.waker());
+            if state.next_id > 0 {
+                let capacity = state.capacity;
+                let current = state.next_id - 1;
+                if let Some(buffer) = state.buffer(current) {
+                    if buffer.len() >= capacity {
+                        // Wait for the current group to be read.
+                        return Poll::Pending;
+                    }
+                }
+            }
+            ready!(state.pull(&inner.notifier));
+        }
+    }
+}
+
+impl<St, K, F> Stream for Group<St, K, F>
+    where St: Stream,
+          K: PartialEq,
+          F: FnMut(&St::Item) -> K,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let inner = &self.inner;
+        let mut state = inner.state.lock().unwrap();
+        loop {
+            if let Some(item) = state.buffer(self.id).and_then(|buffer| buffer.pop_front()) {
+                // `GroupBy` may be waiting for space.
+                inner.notifier.wake_all();
+                return Poll::Ready(Some(item));
+            }
+
+            let is_current = self.id + 1 == state.next_id && state.next.is_none();
+            if !is_current || state.stream.is_none() {
+                return Poll::Ready(None);
+            }
+
+            inner.notifier.register(cx.waker());
+            ready!(state.pull(&inner.notifier));
+        }
+    }
+}
+
+impl<St: Stream, K, F> Drop for Group<St, K, F> {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.inner.state.lock() {
+            let idx = self.id - state.first_id;
+            state.groups[idx] = None;
+            while let Some(None) = state.groups.front() {
+                state.groups.pop_front();
+                state.first_id += 1;
+            }
+            // `GroupBy` may have been waiting for this group.
+            self.inner.notifier.wake_all();
+        }
+    }
+}
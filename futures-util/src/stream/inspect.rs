@@ -97,6 +97,10 @@ impl<St, F> Stream for Inspect<St, F>
             .poll_next(cx)
             .map(|opt| opt.map(|e| inspect(e, self.as_mut().f())))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
@@ -0,0 +1,137 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`intersperse`](super::StreamExt::intersperse) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Intersperse<St: Stream> {
+    stream: St,
+    separator: St::Item,
+    // The item to yield after the separator which was yielded last.
+    next: Option<St::Item>,
+    started: bool,
+}
+
+impl<St: Stream + Unpin> Unpin for Intersperse<St> {}
+
+impl<St> Intersperse<St>
+    where St: Stream,
+          St::Item: Clone,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(next: Option<St::Item>);
+    unsafe_unpinned!(started: bool);
+
+    pub(super) fn new(stream: St, separator: St::Item) -> Intersperse<St> {
+        Intersperse {
+            stream,
+            separator,
+            next: None,
+            started: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut St> {
+        self.stream()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St> FusedStream for Intersperse<St>
+    where St: Stream + FusedStream,
+          St::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.next.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St> Stream for Intersperse<St>
+    where St: Stream,
+          St::Item: Clone,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St::Item>> {
+        if let Some(item) = self.as_mut().next().take() {
+            return Poll::Ready(Some(item));
+        }
+
+        // A separator is only yielded once the item after it is known.
+        let item = match ready!(self.as_mut().stream().poll_next(cx)) {
+            Some(item) => item,
+            None => return Poll::Ready(None),
+        };
+        if self.started {
+            *self.as_mut().next() = Some(item);
+            Poll::Ready(Some(self.separator.clone()))
+        } else {
+            *self.as_mut().started() = true;
+            Poll::Ready(Some(item))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending_len = if self.next.is_some() { 1 } else { 0 };
+        let started = self.started;
+        // Every item but the first one is preceded by a separator.
+        let len = |n: usize| {
+            match n {
+                0 => Some(0),
+                n if started => n.checked_mul(2),
+                n => n.checked_mul(2).map(|len| len - 1),
+            }
+        };
+
+        let (lower, upper) = self.stream.size_hint();
+        let lower = len(lower).unwrap_or(usize::max_value()).saturating_add(pending_len);
+        let upper = match upper {
+            Some(x) => len(x).and_then(|len| len.checked_add(pending_len)),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Item> Sink<Item> for Intersperse<S>
+    where S: Stream + Sink<Item>,
+          S::Item: Clone,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
@@ -3,7 +3,7 @@ use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
 
 /// Stream for the [`iter`] function.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[must_use = "streams do nothing unless polled"]
 pub struct Iter<I> {
     iter: I,
@@ -42,4 +42,8 @@ impl<I> Stream for Iter<I>
     fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<I::Item>> {
         Poll::Ready(self.iter.next())
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
@@ -0,0 +1,74 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`max_by_key`](super::StreamExt::max_by_key) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MaxByKey<St: Stream, K, F> {
+    stream: St,
+    f: F,
+    max: Option<(K, St::Item)>,
+}
+
+impl<St: Stream + Unpin, K, F> Unpin for MaxByKey<St, K, F> {}
+
+impl<St, K, F> fmt::Debug for MaxByKey<St, K, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MaxByKey")
+            .field("stream", &self.stream)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<St, K, F> MaxByKey<St, K, F>
+    where St: Stream,
+          K: Ord,
+          F: FnMut(&St::Item) -> K,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(max: Option<(K, St::Item)>);
+
+    pub(super) fn new(stream: St, f: F) -> MaxByKey<St, K, F> {
+        MaxByKey { stream, f, max: None }
+    }
+}
+
+impl<St, K, F> FusedFuture for MaxByKey<St, K, F>
+    where St: Stream + FusedStream,
+          K: Ord,
+          F: FnMut(&St::Item) -> K,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, K, F> Future for MaxByKey<St, K, F>
+    where St: Stream,
+          K: Ord,
+          F: FnMut(&St::Item) -> K,
+{
+    type Output = Option<St::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        while let Some(item) = ready!(self.as_mut().stream().poll_next(cx)) {
+            let key = (self.as_mut().f())(&item);
+            // The last of several maximal items is kept.
+            match &self.max {
+                Some((max, _)) if key < *max => {}
+                _ => *self.as_mut().max() = Some((key, item)),
+            }
+        }
+        Poll::Ready(self.as_mut().max().take().map(|(_, item)| item))
+    }
+}
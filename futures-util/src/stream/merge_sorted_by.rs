@@ -0,0 +1,222 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug};
+use core::pin::Pin;
+
+use futures_core::{Future, Poll, Stream, FusedStream};
+use futures_core::task::Context;
+
+use crate::future::FutureExt;
+use crate::stream::{StreamExt, StreamFuture, FuturesUnordered};
+
+// Resolves to the next item of an input, tagged with the input's id.
+struct NextItem<St> {
+    id: usize,
+    future: StreamFuture<St>,
+}
+
+impl<St: Stream + Unpin> Future for NextItem<St> {
+    type Output = (usize, Option<St::Item>, St);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (item, stream) = ready!(self.future.poll_unpin(cx));
+        Poll::Ready((self.id, item, stream))
+    }
+}
+
+// An input whose next item has been pulled.
+struct Head<St: Stream> {
+    id: usize,
+    item: St::Item,
+    stream: St,
+}
+
+/// Stream for the [`merge_sorted_by`] function.
+///
+/// Each input stream must yield its items in the order given by the
+/// comparison function. Streams can be added while the merge is running with
+/// [`push`](MergeSortedBy::push).
+#[must_use = "streams do nothing unless polled"]
+pub struct MergeSortedBy<St: Stream, F> {
+    // Inputs waiting for their next item.
+    pending: FuturesUnordered<NextItem<St>>,
+    heads: Vec<Head<St>>,
+    next_id: usize,
+    cmp: F,
+    is_terminated: bool,
+}
+
+impl<St: Stream, F> Unpin for MergeSortedBy<St, F> {}
+
+impl<St: Stream, F> Debug for MergeSortedBy<St, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeSortedBy")
+            .field("len", &(self.pending.len() + self.heads.len()))
+            .finish()
+    }
+}
+
+impl<St, F> MergeSortedBy<St, F>
+    where St: Stream + Unpin,
+          F: FnMut(&St::Item, &St::Item) -> Ordering,
+{
+    /// Constructs a new merge without any inputs, which orders items with
+    /// the comparison function `cmp`.
+    ///
+    /// In this state, `MergeSortedBy::poll_next` will return
+    /// `Poll::Ready(None)`.
+    pub fn new(cmp: F) -> MergeSortedBy<St, F> {
+        MergeSortedBy {
+            pending: FuturesUnordered::new(),
+            heads: Vec::new(),
+            next_id: 0,
+            cmp,
+            is_terminated: false,
+        }
+    }
+
+    /// Returns the number of input streams which haven't ended yet.
+    pub fn len(&self) -> usize {
+        self.pending.len() + self.heads.len()
+    }
+
+    /// Returns `true` if all input streams have ended.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Push an input stream into the merge.
+    ///
+    /// Items which have already been yielded are not affected, so the
+    /// output only stays sorted if the items of the new stream don't
+    /// compare less than the last yielded item.
+    ///
+    /// This function will not call `poll` on the submitted stream. The caller
+    /// must ensure that `MergeSortedBy::poll_next` is called in order to
+    /// receive task notifications.
+    pub fn push(&mut self, stream: St) {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.pending.push(NextItem { id, future: stream.into_future() });
+        self.is_terminated = false;
+    }
+}
+
+impl<St, F> Stream for MergeSortedBy<St, F>
+    where St: Stream + Unpin,
+          F: FnMut(&St::Item, &St::Item) -> Ordering,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        // Pull the next item of every input which doesn't have one yet.
+        while !this.pending.is_empty() {
+            match ready!(this.pending.poll_next_unpin(cx)) {
+                Some((id, Some(item), stream)) => {
+                    this.heads.push(Head { id, item, stream });
+                }
+                Some((_, None, _)) => {}
+                None => break,
+            }
+        }
+
+        // Any input could hold the next minimum, so only yield once all of
+        // them have an item or have ended. Ties go to the input which was
+        // pushed first.
+        let mut min = match this.heads.first() {
+            Some(_) => 0,
+            None => {
+                this.is_terminated = true;
+                return Poll::Ready(None);
+            }
+        };
+        for idx in 1..this.heads.len() {
+            let (head, min_head) = (&this.heads[idx], &this.heads[min]);
+            let ordering = (this.cmp)(&head.item, &min_head.item)
+                .then(head.id.cmp(&min_head.id));
+            if ordering == Ordering::Less {
+                min = idx;
+            }
+        }
+
+        let Head { id, item, stream } = this.heads.swap_remove(min);
+        this.pending.push(NextItem { id, future: stream.into_future() });
+        Poll::Ready(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut lower = self.heads.len();
+        let mut upper = Some(self.heads.len());
+        let streams = self.heads.iter()
+            .map(|head| &head.stream)
+            .chain(self.pending.iter().filter_map(|next| next.future.get_ref()));
+        for stream in streams {
+            let (stream_lower, stream_upper) = stream.size_hint();
+            lower = lower.saturating_add(stream_lower);
+            upper = match (upper, stream_upper) {
+                (Some(x), Some(y)) => x.checked_add(y),
+                _ => None,
+            };
+        }
+        (lower, upper)
+    }
+}
+
+impl<St, F> FusedStream for MergeSortedBy<St, F>
+    where St: Stream + Unpin,
+          F: FnMut(&St::Item, &St::Item) -> Ordering,
+{
+    fn is_terminated(&self) -> bool {
+        self.is_terminated
+    }
+}
+
+/// Merges streams whose items are sorted into a single sorted stream.
+///
+/// Each of the streams must yield its items in the order given by the
+/// comparison function `cmp`. The returned stream keeps the next item of
+/// each stream, and yields the least of them once every stream has either
+/// produced its next item or ended. Items which compare equal are yielded
+/// in the order of the streams that produced them.
+///
+/// Note that the returned stream can also be used to dynamically push more
+/// streams into the merge.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt};
+///
+/// let logs = vec![
+///     stream::iter(vec![1, 4, 7]),
+///     stream::iter(vec![2, 5, 8]),
+///     stream::iter(vec![3, 6]),
+/// ];
+/// let merged = stream::merge_sorted_by(logs, |a, b| a.cmp(b));
+///
+/// assert_eq!(merged.collect::<Vec<_>>().await, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+/// # });
+/// ```
+pub fn merge_sorted_by<I, F>(streams: I, cmp: F) -> MergeSortedBy<I::Item, F>
+    where I: IntoIterator,
+          I::Item: Stream + Unpin,
+          F: FnMut(&<I::Item as Stream>::Item, &<I::Item as Stream>::Item) -> Ordering,
+{
+    let mut merge = MergeSortedBy::new(cmp);
+
+    for stream in streams {
+        merge.push(stream);
+    }
+
+    merge
+}
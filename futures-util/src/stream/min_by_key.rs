@@ -0,0 +1,74 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`min_by_key`](super::StreamExt::min_by_key) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MinByKey<St: Stream, K, F> {
+    stream: St,
+    f: F,
+    min: Option<(K, St::Item)>,
+}
+
+impl<St: Stream + Unpin, K, F> Unpin for MinByKey<St, K, F> {}
+
+impl<St, K, F> fmt::Debug for MinByKey<St, K, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MinByKey")
+            .field("stream", &self.stream)
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<St, K, F> MinByKey<St, K, F>
+    where St: Stream,
+          K: Ord,
+          F: FnMut(&St::Item) -> K,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(min: Option<(K, St::Item)>);
+
+    pub(super) fn new(stream: St, f: F) -> MinByKey<St, K, F> {
+        MinByKey { stream, f, min: None }
+    }
+}
+
+impl<St, K, F> FusedFuture for MinByKey<St, K, F>
+    where St: Stream + FusedStream,
+          K: Ord,
+          F: FnMut(&St::Item) -> K,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, K, F> Future for MinByKey<St, K, F>
+    where St: Stream,
+          K: Ord,
+          F: FnMut(&St::Item) -> K,
+{
+    type Output = Option<St::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        while let Some(item) = ready!(self.as_mut().stream().poll_next(cx)) {
+            let key = (self.as_mut().f())(&item);
+            // The first of several minimal items is kept.
+            match &self.min {
+                Some((min, _)) if key >= *min => {}
+                _ => *self.as_mut().min() = Some((key, item)),
+            }
+        }
+        Poll::Ready(self.as_mut().min().take().map(|(_, item)| item))
+    }
+}
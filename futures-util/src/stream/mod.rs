@@ -10,23 +10,43 @@ use futures_core::task::{Context, Poll};
 use futures_sink::Sink;
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use std::hash::Hash;
 use crate::future::Either;
 
 mod iter;
 pub use self::iter::{iter, Iter};
 
+mod all;
+pub use self::all::All;
+
+mod any;
+pub use self::any::Any;
+
 mod repeat;
 pub use self::repeat::{repeat, Repeat};
 
 mod chain;
 pub use self::chain::Chain;
 
+mod cycle;
+pub use self::cycle::Cycle;
+
+mod dedup;
+pub use self::dedup::Dedup;
+
+mod dedup_by_key;
+pub use self::dedup_by_key::DedupByKey;
+
 mod collect;
 pub use self::collect::Collect;
 
 mod concat;
 pub use self::concat::Concat;
 
+mod count;
+pub use self::count::Count;
+
 mod empty;
 pub use self::empty::{empty, Empty};
 
@@ -39,6 +59,9 @@ pub use self::filter::Filter;
 mod filter_map;
 pub use self::filter_map::FilterMap;
 
+mod find;
+pub use self::find::Find;
+
 mod flatten;
 pub use self::flatten::Flatten;
 
@@ -51,19 +74,34 @@ pub use self::forward::Forward;
 mod for_each;
 pub use self::for_each::ForEach;
 
+mod flat_map;
+pub use self::flat_map::FlatMap;
+
 mod fuse;
 pub use self::fuse::Fuse;
 
 mod into_future;
 pub use self::into_future::StreamFuture;
 
+mod intersperse;
+pub use self::intersperse::Intersperse;
+
 mod inspect;
 pub(crate) use self::inspect::inspect; // used by `TryStreamExt::{inspect_ok, inspect_err}`
 pub use self::inspect::Inspect;
 
+mod last;
+pub use self::last::Last;
+
 mod map;
 pub use self::map::Map;
 
+mod max_by_key;
+pub use self::max_by_key::MaxByKey;
+
+mod min_by_key;
+pub use self::min_by_key::MinByKey;
+
 mod next;
 pub use self::next::Next;
 
@@ -76,24 +114,48 @@ pub use self::once::{once, Once};
 mod peek;
 pub use self::peek::Peekable;
 
+mod pairwise;
+pub use self::pairwise::Pairwise;
+
 mod pending;
 pub use self::pending::{pending, Pending};
 
 mod poll_fn;
 pub use self::poll_fn::{poll_fn, PollFn};
 
+mod position;
+pub use self::position::Position;
+
+mod reduce;
+pub use self::reduce::Reduce;
+
 mod select;
 pub use self::select::{select, Select};
 
+mod select_with_strategy;
+pub use self::select_with_strategy::{select_with_strategy, PollNext, SelectWithStrategy};
+
+mod scan;
+pub use self::scan::Scan;
+
 mod skip;
 pub use self::skip::Skip;
 
+mod skip_until;
+pub use self::skip_until::SkipUntil;
+
 mod skip_while;
 pub use self::skip_while::SkipWhile;
 
 mod take;
 pub use self::take::Take;
 
+mod step_by;
+pub use self::step_by::StepBy;
+
+mod take_until;
+pub use self::take_until::TakeUntil;
+
 mod take_while;
 pub use self::take_while::TakeWhile;
 
@@ -111,7 +173,29 @@ mod chunks;
 #[cfg(feature = "alloc")]
 pub use self::chunks::Chunks;
 
+#[cfg(feature = "alloc")]
+mod ready_chunks;
+#[cfg(feature = "alloc")]
+pub use self::ready_chunks::ReadyChunks;
+
+#[cfg(feature = "alloc")]
+mod windows;
+#[cfg(feature = "alloc")]
+pub use self::windows::Windows;
+
+#[cfg(feature = "alloc")]
+mod stream_map;
+#[cfg(feature = "alloc")]
+pub use self::stream_map::StreamMap;
+
 cfg_target_has_atomic! {
+    #[cfg(feature = "alloc")]
+    mod abortable;
+    #[cfg(feature = "alloc")]
+    pub use self::abortable::abortable;
+    #[cfg(feature = "alloc")]
+    pub use crate::future::{Abortable, AbortHandle, AbortRegistration, Aborted, AbortedWith};
+
     #[cfg(feature = "alloc")]
     mod buffer_unordered;
     #[cfg(feature = "alloc")]
@@ -122,6 +206,21 @@ cfg_target_has_atomic! {
     #[cfg(feature = "alloc")]
     pub use self::buffered::Buffered;
 
+    #[cfg(feature = "alloc")]
+    mod concurrency_limit;
+    #[cfg(feature = "std")]
+    pub use self::concurrency_limit::{AimdController, ConcurrencyLimit};
+
+    #[cfg(feature = "alloc")]
+    mod flat_map_unordered;
+    #[cfg(feature = "alloc")]
+    pub use self::flat_map_unordered::FlatMapUnordered;
+
+    #[cfg(feature = "alloc")]
+    mod flatten_unordered;
+    #[cfg(feature = "alloc")]
+    pub use self::flatten_unordered::FlattenUnordered;
+
     #[cfg(feature = "alloc")]
     mod for_each_concurrent;
     #[cfg(feature = "alloc")]
@@ -138,11 +237,21 @@ cfg_target_has_atomic! {
     #[doc(inline)]
     pub use self::futures_unordered::FuturesUnordered;
 
+    #[cfg(feature = "std")]
+    mod futures_map;
+    #[cfg(feature = "std")]
+    pub use self::futures_map::FuturesMap;
+
     #[cfg(feature = "alloc")]
     mod split;
     #[cfg(feature = "alloc")]
     pub use self::split::{SplitStream, SplitSink, ReuniteError};
 
+    #[cfg(feature = "alloc")]
+    mod merge_sorted_by;
+    #[cfg(feature = "alloc")]
+    pub use self::merge_sorted_by::{merge_sorted_by, MergeSortedBy};
+
     #[cfg(feature = "alloc")]
     mod select_all;
     #[cfg(feature = "alloc")]
@@ -154,6 +263,34 @@ mod catch_unwind;
 #[cfg(feature = "std")]
 pub use self::catch_unwind::CatchUnwind;
 
+#[cfg(feature = "std")]
+mod shared;
+#[cfg(feature = "std")]
+pub use self::shared::Shared;
+
+#[cfg(feature = "std")]
+mod distinct_by_key;
+#[cfg(feature = "std")]
+pub use self::distinct_by_key::DistinctByKey;
+
+#[cfg(feature = "std")]
+mod fork;
+
+#[cfg(feature = "std")]
+mod partition;
+#[cfg(feature = "std")]
+pub use self::partition::Partition;
+
+#[cfg(feature = "std")]
+mod unzip;
+#[cfg(feature = "std")]
+pub use self::unzip::{UnzipLeft, UnzipRight};
+
+#[cfg(feature = "std")]
+mod group_by;
+#[cfg(feature = "std")]
+pub use self::group_by::{Group, GroupBy};
+
 impl<T: ?Sized> StreamExt for T where T: Stream {}
 
 /// An extension trait for `Stream`s that provides a variety of convenient
@@ -508,83 +645,62 @@ pub trait StreamExt: Stream {
         Fold::new(self, f, init)
     }
 
-    /// Flattens a stream of streams into just one continuous stream.
+    /// Execute an accumulating asynchronous computation over a stream,
+    /// starting with its first item.
+    ///
+    /// This combinator works like [`StreamExt::fold`], except that the first
+    /// item of the stream is used as the initial value. The returned future
+    /// resolves to `None` if the stream is empty.
     ///
     /// # Examples
     ///
-    // TODO: https://github.com/rust-lang-nursery/futures-rs/issues/1657
-    /// ```ignore
+    /// ```
     /// #![feature(async_await)]
     /// # futures::executor::block_on(async {
-    /// use futures::channel::mpsc;
-    /// use futures::stream::StreamExt;
-    /// use std::thread;
-    ///
-    /// let (tx1, rx1) = mpsc::unbounded();
-    /// let (tx2, rx2) = mpsc::unbounded();
-    /// let (tx3, rx3) = mpsc::unbounded();
-    ///
-    /// thread::spawn(move || {
-    ///     tx1.unbounded_send(1).unwrap();
-    ///     tx1.unbounded_send(2).unwrap();
-    /// });
-    /// thread::spawn(move || {
-    ///     tx2.unbounded_send(3).unwrap();
-    ///     tx2.unbounded_send(4).unwrap();
-    /// });
-    /// thread::spawn(move || {
-    ///     tx3.unbounded_send(rx1).unwrap();
-    ///     tx3.unbounded_send(rx2).unwrap();
-    /// });
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
     ///
-    /// let output = rx3.flatten().collect::<Vec<i32>>().await;
-    /// assert_eq!(output, vec![1, 2, 3, 4]);
+    /// let number_stream = stream::iter(1..6);
+    /// let product = number_stream.reduce(|acc, x| future::ready(acc * x));
+    /// assert_eq!(product.await, Some(120));
     /// # });
     /// ```
-    fn flatten(self) -> Flatten<Self>
-        where Self::Item: Stream,
+    fn reduce<Fut, F>(self, f: F) -> Reduce<Self, Fut, F>
+        where F: FnMut(Self::Item, Self::Item) -> Fut,
+              Fut: Future<Output = Self::Item>,
               Self: Sized
     {
-        Flatten::new(self)
+        Reduce::new(self, f)
     }
 
-    /// Skip elements on this stream while the provided asynchronous predicate
-    /// resolves to `true`.
+    /// Counts the items of the stream.
     ///
-    /// This function, like `Iterator::skip_while`, will skip elements on the
-    /// stream until the predicate `f` resolves to `false`. Once one element
-    /// returns false all future elements will be returned from the underlying
-    /// stream.
+    /// The returned future drives the stream to completion and resolves to
+    /// the number of items it yielded.
     ///
     /// # Examples
     ///
     /// ```
     /// #![feature(async_await)]
     /// # futures::executor::block_on(async {
-    /// use futures::future;
     /// use futures::stream::{self, StreamExt};
     ///
     /// let stream = stream::iter(1..=10);
-    ///
-    /// let stream = stream.skip_while(|x| future::ready(*x <= 5));
-    ///
-    /// assert_eq!(vec![6, 7, 8, 9, 10], stream.collect::<Vec<_>>().await);
+    /// assert_eq!(stream.count().await, 10);
     /// # });
     /// ```
-    fn skip_while<Fut, F>(self, f: F) -> SkipWhile<Self, Fut, F>
-        where F: FnMut(&Self::Item) -> Fut,
-              Fut: Future<Output = bool>,
-              Self: Sized
+    fn count(self) -> Count<Self>
+        where Self: Sized
     {
-        SkipWhile::new(self, f)
+        Count::new(self)
     }
 
-    /// Take elements from this stream while the provided asynchronous predicate
-    /// resolves to `true`.
+    /// Checks whether any item of the stream matches an asynchronous
+    /// predicate.
     ///
-    /// This function, like `Iterator::take_while`, will take elements from the
-    /// stream until the predicate `f` resolves to `false`. Once one element
-    /// returns false it will always return that the stream is done.
+    /// The returned future resolves to `true` as soon as the predicate
+    /// resolves to `true` for an item, without pulling any further items from
+    /// the stream. If the stream ends before that, it resolves to `false`.
     ///
     /// # Examples
     ///
@@ -594,33 +710,25 @@ pub trait StreamExt: Stream {
     /// use futures::future;
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter(1..=10);
-    ///
-    /// let stream = stream.take_while(|x| future::ready(*x <= 5));
-    ///
-    /// assert_eq!(vec![1, 2, 3, 4, 5], stream.collect::<Vec<_>>().await);
+    /// let mut stream = stream::iter(1..=10);
+    /// assert!((&mut stream).any(|x| future::ready(x % 4 == 0)).await);
+    /// assert_eq!(stream.next().await, Some(5));
     /// # });
     /// ```
-    fn take_while<Fut, F>(self, f: F) -> TakeWhile<Self, Fut, F>
-        where F: FnMut(&Self::Item) -> Fut,
+    fn any<Fut, F>(self, f: F) -> Any<Self, Fut, F>
+        where F: FnMut(Self::Item) -> Fut,
               Fut: Future<Output = bool>,
               Self: Sized
     {
-        TakeWhile::new(self, f)
+        Any::new(self, f)
     }
 
-    /// Runs this stream to completion, executing the provided asynchronous
-    /// closure for each element on the stream.
-    ///
-    /// The closure provided will be called for each item this stream produces,
-    /// yielding a future. That future will then be executed to completion
-    /// before moving on to the next item.
+    /// Checks whether all items of the stream match an asynchronous
+    /// predicate.
     ///
-    /// The returned value is a `Future` where the `Output` type is `()`; it is
-    /// executed entirely for its side effects.
-    ///
-    /// To process each item in the stream and produce another stream instead
-    /// of a single future, use `then` instead.
+    /// The returned future resolves to `false` as soon as the predicate
+    /// resolves to `false` for an item, without pulling any further items from
+    /// the stream. If the stream ends before that, it resolves to `true`.
     ///
     /// # Examples
     ///
@@ -630,94 +738,77 @@ pub trait StreamExt: Stream {
     /// use futures::future;
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let mut x = 0;
-    ///
-    /// {
-    ///     let fut = stream::repeat(1).take(3).for_each(|item| {
-    ///         x += item;
-    ///         future::ready(())
-    ///     });
-    ///     fut.await;
-    /// }
-    ///
-    /// assert_eq!(x, 3);
+    /// let stream = stream::iter(vec![2, 4, 6]);
+    /// assert!(stream.all(|x| future::ready(x % 2 == 0)).await);
     /// # });
     /// ```
-    fn for_each<Fut, F>(self, f: F) -> ForEach<Self, Fut, F>
+    fn all<Fut, F>(self, f: F) -> All<Self, Fut, F>
         where F: FnMut(Self::Item) -> Fut,
-              Fut: Future<Output = ()>,
+              Fut: Future<Output = bool>,
               Self: Sized
     {
-        ForEach::new(self, f)
+        All::new(self, f)
     }
 
-    /// Runs this stream to completion, executing the provided asynchronous
-    /// closure for each element on the stream concurrently as elements become
-    /// available.
+    /// Searches the stream for the first item which matches an asynchronous
+    /// predicate.
     ///
-    /// This is similar to [`StreamExt::for_each`], but the futures
-    /// produced by the closure are run concurrently (but not in parallel--
-    /// this combinator does not introduce any threads).
+    /// The returned future resolves to that item without pulling any further
+    /// items from the stream, or to `None` if the stream ends before one was
+    /// found.
     ///
-    /// The closure provided will be called for each item this stream produces,
-    /// yielding a future. That future will then be executed to completion
-    /// concurrently with the other futures produced by the closure.
+    /// # Examples
     ///
-    /// The first argument is an optional limit on the number of concurrent
-    /// futures. If this limit is not `None`, no more than `limit` futures
-    /// will be run concurrently. The `limit` argument is of type
-    /// `Into<Option<usize>>`, and so can be provided as either `None`,
-    /// `Some(10)`, or just `10`. Note: a limit of zero is interpreted as
-    /// no limit at all, and will have the same result as passing in `None`.
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
     ///
-    /// This method is only available when the `std` or `alloc` feature of this
-    /// library is activated, and it is activated by default.
+    /// let stream = stream::iter(1..=10);
+    /// assert_eq!(stream.find(|x| future::ready(x * x > 20)).await, Some(5));
+    /// # });
+    /// ```
+    fn find<Fut, F>(self, f: F) -> Find<Self, Fut, F>
+        where F: FnMut(&Self::Item) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized
+    {
+        Find::new(self, f)
+    }
+
+    /// Searches the stream for the first item which matches an asynchronous
+    /// predicate, returning its index.
+    ///
+    /// The returned future resolves to the index of that item without pulling
+    /// any further items from the stream, or to `None` if the stream ends
+    /// before one was found.
     ///
     /// # Examples
     ///
     /// ```
     /// #![feature(async_await)]
     /// # futures::executor::block_on(async {
-    /// use futures::channel::oneshot;
+    /// use futures::future;
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let (tx1, rx1) = oneshot::channel();
-    /// let (tx2, rx2) = oneshot::channel();
-    /// let (tx3, rx3) = oneshot::channel();
-    ///
-    /// let fut = stream::iter(vec![rx1, rx2, rx3]).for_each_concurrent(
-    ///     /* limit */ 2,
-    ///     async move |rx| {
-    ///         rx.await.unwrap();
-    ///     }
-    /// );
-    /// tx1.send(()).unwrap();
-    /// tx2.send(()).unwrap();
-    /// tx3.send(()).unwrap();
-    /// fut.await;
-    /// # })
+    /// let stream = stream::iter(vec![1, 3, 4, 5]);
+    /// assert_eq!(stream.position(|x| future::ready(x % 2 == 0)).await, Some(2));
+    /// # });
     /// ```
-    #[cfg_attr(
-        feature = "cfg-target-has-atomic",
-        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
-    )]
-    #[cfg(feature = "alloc")]
-    fn for_each_concurrent<Fut, F>(
-        self,
-        limit: impl Into<Option<usize>>,
-        f: F,
-    ) -> ForEachConcurrent<Self, Fut, F>
+    fn position<Fut, F>(self, f: F) -> Position<Self, Fut, F>
         where F: FnMut(Self::Item) -> Fut,
-              Fut: Future<Output = ()>,
-              Self: Sized,
+              Fut: Future<Output = bool>,
+              Self: Sized
     {
-        ForEachConcurrent::new(self, limit.into(), f)
+        Position::new(self, f)
     }
 
-    /// Creates a new stream of at most `n` items of the underlying stream.
+    /// Returns the item of the stream for which the given function returns
+    /// the minimum key.
     ///
-    /// Once `n` items have been yielded from this stream then it will always
-    /// return that the stream is done.
+    /// If several items are equally minimal, the first one is returned. The
+    /// returned future resolves to `None` if the stream is empty.
     ///
     /// # Examples
     ///
@@ -726,21 +817,23 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter(1..=10).take(3);
-    ///
-    /// assert_eq!(vec![1, 2, 3], stream.collect::<Vec<_>>().await);
+    /// let stream = stream::iter(vec![-3, 1, -1, 2]);
+    /// assert_eq!(stream.min_by_key(|x: &i32| x.abs()).await, Some(1));
     /// # });
     /// ```
-    fn take(self, n: u64) -> Take<Self>
-        where Self: Sized
+    fn min_by_key<K, F>(self, f: F) -> MinByKey<Self, K, F>
+        where F: FnMut(&Self::Item) -> K,
+              K: Ord,
+              Self: Sized
     {
-        Take::new(self, n)
+        MinByKey::new(self, f)
     }
 
-    /// Creates a new stream which skips `n` items of the underlying stream.
+    /// Returns the item of the stream for which the given function returns
+    /// the maximum key.
     ///
-    /// Once `n` items have been skipped from this stream then it will always
-    /// return the remaining items on this stream.
+    /// If several items are equally maximal, the last one is returned. The
+    /// returned future resolves to `None` if the stream is empty.
     ///
     /// # Examples
     ///
@@ -749,36 +842,575 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter(1..=10).skip(5);
-    ///
-    /// assert_eq!(vec![6, 7, 8, 9, 10], stream.collect::<Vec<_>>().await);
+    /// let stream = stream::iter(vec![-3, 1, 3, 2]);
+    /// assert_eq!(stream.max_by_key(|x: &i32| x.abs()).await, Some(3));
     /// # });
     /// ```
-    fn skip(self, n: u64) -> Skip<Self>
-        where Self: Sized
+    fn max_by_key<K, F>(self, f: F) -> MaxByKey<Self, K, F>
+        where F: FnMut(&Self::Item) -> K,
+              K: Ord,
+              Self: Sized
     {
-        Skip::new(self, n)
+        MaxByKey::new(self, f)
     }
 
-    /// Fuse a stream such that [`poll_next`](Stream::poll_next) will never
-    /// again be called once it has finished. This method can be used t turn
-    /// any `Stream` into a `FusedStream`.
-    ///
-    /// Normally, once a stream has returned [`None`] from
-    /// [`poll_next`](Stream::poll_next) any further calls could exhibit bad
-    /// behavior such as block forever, panic, never return, etc. If it is known
-    /// that [`poll_next`](Stream::poll_next) may be called after stream
-    /// has already finished, then this method can be used to ensure that it has
-    /// defined semantics.
+    /// Returns the last item of the stream.
     ///
-    /// The [`poll_next`](Stream::poll_next) method of a `fuse`d stream
-    /// is guaranteed to return [`None`] after the underlying stream has
-    /// finished.
+    /// The returned future drives the stream to completion and resolves to
+    /// its last item, or to `None` if the stream is empty.
     ///
     /// # Examples
     ///
     /// ```
-    /// use futures::executor::block_on_stream;
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3);
+    /// assert_eq!(stream.last().await, Some(3));
+    /// # });
+    /// ```
+    fn last(self) -> Last<Self>
+        where Self: Sized
+    {
+        Last::new(self)
+    }
+
+    /// Flattens a stream of streams into just one continuous stream.
+    ///
+    /// # Examples
+    ///
+    // TODO: https://github.com/rust-lang-nursery/futures-rs/issues/1657
+    /// ```ignore
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::stream::StreamExt;
+    /// use std::thread;
+    ///
+    /// let (tx1, rx1) = mpsc::unbounded();
+    /// let (tx2, rx2) = mpsc::unbounded();
+    /// let (tx3, rx3) = mpsc::unbounded();
+    ///
+    /// thread::spawn(move || {
+    ///     tx1.unbounded_send(1).unwrap();
+    ///     tx1.unbounded_send(2).unwrap();
+    /// });
+    /// thread::spawn(move || {
+    ///     tx2.unbounded_send(3).unwrap();
+    ///     tx2.unbounded_send(4).unwrap();
+    /// });
+    /// thread::spawn(move || {
+    ///     tx3.unbounded_send(rx1).unwrap();
+    ///     tx3.unbounded_send(rx2).unwrap();
+    /// });
+    ///
+    /// let output = rx3.flatten().collect::<Vec<i32>>().await;
+    /// assert_eq!(output, vec![1, 2, 3, 4]);
+    /// # });
+    /// ```
+    fn flatten(self) -> Flatten<Self>
+        where Self::Item: Stream,
+              Self: Sized
+    {
+        Flatten::new(self)
+    }
+
+    /// Maps a stream like [`StreamExt::map`] but flattens nested `Stream`s.
+    ///
+    /// [`StreamExt::map`] is very useful, but if it produces a `Stream` instead,
+    /// you would have to chain combinators like `.map(f).flatten()` while this
+    /// combinator provides ability to write `.flat_map(f)` instead of chaining.
+    ///
+    /// The provided closure which produces inner streams is executed over all
+    /// elements of the stream as the previous inner stream is exhausted and
+    /// the next item is obtained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3);
+    /// let stream = stream.flat_map(|x| stream::iter(vec![x; x]));
+    ///
+    /// assert_eq!(vec![1, 2, 2, 3, 3, 3], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn flat_map<U, F>(self, f: F) -> FlatMap<Self, U, F>
+        where F: FnMut(Self::Item) -> U,
+              U: Stream,
+              Self: Sized
+    {
+        FlatMap::new(self, f)
+    }
+
+    /// Flattens a stream of streams into just one continuous stream, polling
+    /// up to `limit` inner streams concurrently.
+    ///
+    /// Unlike [`StreamExt::flatten`], the items of the inner streams are
+    /// interleaved in the order in which they become ready. Once an inner
+    /// stream is exhausted, the next one is pulled from this stream.
+    ///
+    /// The inner streams need to be `Unpin`; streams which aren't can be
+    /// pinned with `Box::pin`.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (tx1, rx1) = mpsc::unbounded();
+    /// let (tx2, rx2) = mpsc::unbounded();
+    ///
+    /// let mut stream = stream::iter(vec![rx1, rx2]).flatten_unordered(2);
+    ///
+    /// tx2.unbounded_send(2).unwrap();
+    /// assert_eq!(stream.next().await, Some(2));
+    ///
+    /// tx1.unbounded_send(1).unwrap();
+    /// assert_eq!(stream.next().await, Some(1));
+    ///
+    /// drop((tx1, tx2));
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is zero.
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "alloc")]
+    fn flatten_unordered(self, limit: usize) -> FlattenUnordered<Self>
+        where Self::Item: Stream + Unpin,
+              Self: Sized
+    {
+        FlattenUnordered::new(self, limit)
+    }
+
+    /// Maps a stream like [`StreamExt::map`] but flattens nested `Stream`s,
+    /// polling up to `limit` of them concurrently.
+    ///
+    /// This is the concurrent version of [`StreamExt::flat_map`], see
+    /// [`StreamExt::flatten_unordered`] for how the inner streams are polled.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3);
+    /// let stream = stream.flat_map_unordered(3, |x| stream::iter(vec![x; x]));
+    ///
+    /// let mut values = stream.collect::<Vec<_>>().await;
+    /// values.sort();
+    /// assert_eq!(vec![1, 2, 2, 3, 3, 3], values);
+    /// # });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is zero.
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "alloc")]
+    fn flat_map_unordered<U, F>(self, limit: usize, f: F) -> FlatMapUnordered<Self, U, F>
+        where F: FnMut(Self::Item) -> U,
+              U: Stream + Unpin,
+              Self: Sized
+    {
+        FlatMapUnordered::new(self, limit, f)
+    }
+
+    /// Combinator similar to [`StreamExt::fold`] that holds internal state
+    /// and produces a new stream.
+    ///
+    /// Accepts an initial state and a closure which is called with a mutable
+    /// reference to the state and each item of the stream. The future it
+    /// returns resolves to the item to yield, or to `None` to end the stream,
+    /// like `Iterator::scan`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10);
+    ///
+    /// let stream = stream.scan(0, |state, x| {
+    ///     *state += x;
+    ///     future::ready(if *state < 10 { Some(*state) } else { None })
+    /// });
+    ///
+    /// assert_eq!(vec![1, 3, 6], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn scan<S, B, Fut, F>(self, initial_state: S, f: F) -> Scan<Self, S, Fut, F>
+        where F: FnMut(&mut S, Self::Item) -> Fut,
+              Fut: Future<Output = Option<B>>,
+              Self: Sized
+    {
+        Scan::new(self, initial_state, f)
+    }
+
+    /// Skip elements on this stream while the provided asynchronous predicate
+    /// resolves to `true`.
+    ///
+    /// This function, like `Iterator::skip_while`, will skip elements on the
+    /// stream until the predicate `f` resolves to `false`. Once one element
+    /// returns false all future elements will be returned from the underlying
+    /// stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10);
+    ///
+    /// let stream = stream.skip_while(|x| future::ready(*x <= 5));
+    ///
+    /// assert_eq!(vec![6, 7, 8, 9, 10], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn skip_while<Fut, F>(self, f: F) -> SkipWhile<Self, Fut, F>
+        where F: FnMut(&Self::Item) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized
+    {
+        SkipWhile::new(self, f)
+    }
+
+    /// Take elements from this stream while the provided asynchronous predicate
+    /// resolves to `true`.
+    ///
+    /// This function, like `Iterator::take_while`, will take elements from the
+    /// stream until the predicate `f` resolves to `false`. Once one element
+    /// returns false it will always return that the stream is done.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10);
+    ///
+    /// let stream = stream.take_while(|x| future::ready(*x <= 5));
+    ///
+    /// assert_eq!(vec![1, 2, 3, 4, 5], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn take_while<Fut, F>(self, f: F) -> TakeWhile<Self, Fut, F>
+        where F: FnMut(&Self::Item) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized
+    {
+        TakeWhile::new(self, f)
+    }
+
+    /// Take elements from this stream until the provided future resolves.
+    ///
+    /// The future is polled before each item of the stream. Once it has
+    /// resolved, the stream is done, and its output is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (stop_tx, stop_rx) = oneshot::channel::<()>();
+    /// let mut stream = stream::iter(1..=10).take_until(stop_rx);
+    ///
+    /// assert_eq!(stream.next().await, Some(1));
+    /// assert_eq!(stream.next().await, Some(2));
+    /// stop_tx.send(()).unwrap();
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
+    fn take_until<Fut>(self, fut: Fut) -> TakeUntil<Self, Fut>
+        where Fut: Future,
+              Self: Sized
+    {
+        TakeUntil::new(self, fut)
+    }
+
+    /// Skip elements on this stream until the provided future resolves.
+    ///
+    /// Until the future has resolved, the items of the stream are pulled and
+    /// dropped. Once it has resolved, all further items are returned from the
+    /// underlying stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3).skip_until(future::ready(()));
+    ///
+    /// assert_eq!(vec![1, 2, 3], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn skip_until<Fut>(self, fut: Fut) -> SkipUntil<Self, Fut>
+        where Fut: Future,
+              Self: Sized
+    {
+        SkipUntil::new(self, fut)
+    }
+
+    /// Runs this stream to completion, executing the provided asynchronous
+    /// closure for each element on the stream.
+    ///
+    /// The closure provided will be called for each item this stream produces,
+    /// yielding a future. That future will then be executed to completion
+    /// before moving on to the next item.
+    ///
+    /// The returned value is a `Future` where the `Output` type is `()`; it is
+    /// executed entirely for its side effects.
+    ///
+    /// To process each item in the stream and produce another stream instead
+    /// of a single future, use `then` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut x = 0;
+    ///
+    /// {
+    ///     let fut = stream::repeat(1).take(3).for_each(|item| {
+    ///         x += item;
+    ///         future::ready(())
+    ///     });
+    ///     fut.await;
+    /// }
+    ///
+    /// assert_eq!(x, 3);
+    /// # });
+    /// ```
+    fn for_each<Fut, F>(self, f: F) -> ForEach<Self, Fut, F>
+        where F: FnMut(Self::Item) -> Fut,
+              Fut: Future<Output = ()>,
+              Self: Sized
+    {
+        ForEach::new(self, f)
+    }
+
+    /// Runs this stream to completion, executing the provided asynchronous
+    /// closure for each element on the stream concurrently as elements become
+    /// available.
+    ///
+    /// This is similar to [`StreamExt::for_each`], but the futures
+    /// produced by the closure are run concurrently (but not in parallel--
+    /// this combinator does not introduce any threads).
+    ///
+    /// The closure provided will be called for each item this stream produces,
+    /// yielding a future. That future will then be executed to completion
+    /// concurrently with the other futures produced by the closure.
+    ///
+    /// The first argument is an optional limit on the number of concurrent
+    /// futures. If this limit is not `None`, no more than `limit` futures
+    /// will be run concurrently. The `limit` argument is of type
+    /// `Into<Option<usize>>`, and so can be provided as either `None`,
+    /// `Some(10)`, or just `10`. Note: a limit of zero is interpreted as
+    /// no limit at all, and will have the same result as passing in `None`.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (tx1, rx1) = oneshot::channel();
+    /// let (tx2, rx2) = oneshot::channel();
+    /// let (tx3, rx3) = oneshot::channel();
+    ///
+    /// let fut = stream::iter(vec![rx1, rx2, rx3]).for_each_concurrent(
+    ///     /* limit */ 2,
+    ///     async move |rx| {
+    ///         rx.await.unwrap();
+    ///     }
+    /// );
+    /// tx1.send(()).unwrap();
+    /// tx2.send(()).unwrap();
+    /// tx3.send(()).unwrap();
+    /// fut.await;
+    /// # })
+    /// ```
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "alloc")]
+    fn for_each_concurrent<Fut, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> ForEachConcurrent<Self, Fut, F>
+        where F: FnMut(Self::Item) -> Fut,
+              Fut: Future<Output = ()>,
+              Self: Sized,
+    {
+        ForEachConcurrent::new(self, limit.into(), f)
+    }
+
+    /// Runs this stream to completion, executing the provided asynchronous
+    /// closure for each element on the stream concurrently as elements become
+    /// available, with a concurrency limit which can change at runtime.
+    ///
+    /// This is like [`StreamExt::for_each_concurrent`], except that the
+    /// number of concurrently running futures is capped by the current value
+    /// of the shared `limit`. See [`ConcurrencyLimit`] for how the limit can
+    /// be changed.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, ConcurrencyLimit, StreamExt};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let limit = ConcurrencyLimit::new(2);
+    /// let processed = AtomicUsize::new(0);
+    ///
+    /// let fut = stream::iter(0..10).for_each_concurrent_with_limit(
+    ///     limit.clone(),
+    ///     |_| async { processed.fetch_add(1, Ordering::SeqCst); },
+    /// );
+    ///
+    /// // The limit can be changed while the future is running.
+    /// limit.set(4);
+    /// fut.await;
+    /// assert_eq!(processed.load(Ordering::SeqCst), 10);
+    /// # })
+    /// ```
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "std")]
+    fn for_each_concurrent_with_limit<Fut, F>(
+        self,
+        limit: ConcurrencyLimit,
+        f: F,
+    ) -> ForEachConcurrent<Self, Fut, F>
+        where F: FnMut(Self::Item) -> Fut,
+              Fut: Future<Output = ()>,
+              Self: Sized,
+    {
+        ForEachConcurrent::with_limit(self, limit, f)
+    }
+
+    /// Creates a new stream of at most `n` items of the underlying stream.
+    ///
+    /// Once `n` items have been yielded from this stream then it will always
+    /// return that the stream is done.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10).take(3);
+    ///
+    /// assert_eq!(vec![1, 2, 3], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn take(self, n: u64) -> Take<Self>
+        where Self: Sized
+    {
+        Take::new(self, n)
+    }
+
+    /// Creates a new stream which skips `n` items of the underlying stream.
+    ///
+    /// Once `n` items have been skipped from this stream then it will always
+    /// return the remaining items on this stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=10).skip(5);
+    ///
+    /// assert_eq!(vec![6, 7, 8, 9, 10], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn skip(self, n: u64) -> Skip<Self>
+        where Self: Sized
+    {
+        Skip::new(self, n)
+    }
+
+    /// Fuse a stream such that [`poll_next`](Stream::poll_next) will never
+    /// again be called once it has finished. This method can be used t turn
+    /// any `Stream` into a `FusedStream`.
+    ///
+    /// Normally, once a stream has returned [`None`] from
+    /// [`poll_next`](Stream::poll_next) any further calls could exhibit bad
+    /// behavior such as block forever, panic, never return, etc. If it is known
+    /// that [`poll_next`](Stream::poll_next) may be called after stream
+    /// has already finished, then this method can be used to ensure that it has
+    /// defined semantics.
+    ///
+    /// The [`poll_next`](Stream::poll_next) method of a `fuse`d stream
+    /// is guaranteed to return [`None`] after the underlying stream has
+    /// finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on_stream;
     /// use futures::stream::{self, StreamExt};
     /// use futures::task::Poll;
     ///
@@ -833,29 +1465,201 @@ pub trait StreamExt: Stream {
     /// assert_eq!(sum, 7);
     /// # });
     /// ```
-    fn by_ref(&mut self) -> &mut Self {
-        self
+    fn by_ref(&mut self) -> &mut Self {
+        self
+    }
+
+    /// Catches unwinding panics while polling the stream.
+    ///
+    /// Caught panic (if any) will be the last element of the resulting stream.
+    ///
+    /// In general, panics within a stream can propagate all the way out to the
+    /// task level. This combinator makes it possible to halt unwinding within
+    /// the stream itself. It's most commonly used within task executors. This
+    /// method should not be used for error handling.
+    ///
+    /// Note that this method requires the `UnwindSafe` bound from the standard
+    /// library. This isn't always applied automatically, and the standard
+    /// library provides an `AssertUnwindSafe` wrapper type to apply it
+    /// after-the fact. To assist using this method, the [`Stream`] trait is
+    /// also implemented for `AssertUnwindSafe<St>` where `St` implements
+    /// [`Stream`].
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![Some(10), None, Some(11)]);
+    /// // Panic on second element
+    /// let stream_panicking = stream.map(|o| o.unwrap());
+    /// // Collect all the results
+    /// let stream = stream_panicking.catch_unwind();
+    ///
+    /// let results: Vec<Result<i32, _>> = stream.collect().await;
+    /// match results[0] {
+    ///     Ok(10) => {}
+    ///     _ => panic!("unexpected result!"),
+    /// }
+    /// assert!(results[1].is_err());
+    /// assert_eq!(results.len(), 2);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn catch_unwind(self) -> CatchUnwind<Self>
+        where Self: Sized + std::panic::UnwindSafe
+    {
+        CatchUnwind::new(self)
+    }
+
+    /// Create a cloneable handle to this stream, with which several
+    /// consumers can each receive every item of the stream.
+    ///
+    /// Each clone of the returned stream yields all items of the underlying
+    /// stream from the point at which it was cloned, so the items must be
+    /// `Clone`. The underlying stream is polled by whichever clone is the
+    /// furthest ahead, and items are kept in a buffer until all clones have
+    /// yielded them. Once `capacity` items are buffered, the clones which are
+    /// ahead wait for the slowest clone to catch up.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream1 = stream::iter(1..=3).shared(2);
+    /// let stream2 = stream1.clone();
+    ///
+    /// let (items1, items2) = future::join(
+    ///     stream1.collect::<Vec<_>>(),
+    ///     stream2.collect::<Vec<_>>(),
+    /// ).await;
+    /// assert_eq!(items1, vec![1, 2, 3]);
+    /// assert_eq!(items2, vec![1, 2, 3]);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn shared(self, capacity: usize) -> Shared<Self>
+        where Self: Sized,
+              Self::Item: Clone,
+    {
+        Shared::new(self, capacity)
+    }
+
+    /// Splits this stream into two, the first of which yields the items for
+    /// which the predicate `f` returns `true`, and the second the others.
+    ///
+    /// The underlying stream is polled by whichever of the two streams needs
+    /// an item, and the items for the other one are buffered until it yields
+    /// them. Once `capacity` items are buffered for one of the streams, the
+    /// other one waits for it to catch up. Items for a stream which has been
+    /// dropped are discarded.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (evens, odds) = stream::iter(1..=6).partition(4, |x| x % 2 == 0);
+    ///
+    /// let (evens, odds) = future::join(
+    ///     evens.collect::<Vec<_>>(),
+    ///     odds.collect::<Vec<_>>(),
+    /// ).await;
+    /// assert_eq!(evens, vec![2, 4, 6]);
+    /// assert_eq!(odds, vec![1, 3, 5]);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn partition<F>(self, capacity: usize, f: F) -> (Partition<Self, F>, Partition<Self, F>)
+        where F: FnMut(&Self::Item) -> bool,
+              Self: Sized,
+    {
+        Partition::new(self, capacity, f)
+    }
+
+    /// Splits a stream of pairs into a stream of the first elements and a
+    /// stream of the second elements.
+    ///
+    /// The underlying stream is polled by whichever of the two streams needs
+    /// an item, and the elements for the other one are buffered until it
+    /// yields them. Once `capacity` elements are buffered for one of the
+    /// streams, the other one waits for it to catch up. Elements for a
+    /// stream which has been dropped are discarded.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (numbers, letters) = stream::iter(vec![(1, 'a'), (2, 'b')]).unzip(2);
+    ///
+    /// let (numbers, letters) = future::join(
+    ///     numbers.collect::<Vec<_>>(),
+    ///     letters.collect::<String>(),
+    /// ).await;
+    /// assert_eq!(numbers, vec![1, 2]);
+    /// assert_eq!(letters, "ab");
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn unzip<A, B>(self, capacity: usize) -> (UnzipLeft<Self, A, B>, UnzipRight<Self, A, B>)
+        where Self: Stream<Item = (A, B)> + Sized,
+    {
+        UnzipLeft::new(self, capacity)
     }
 
-    /// Catches unwinding panics while polling the stream.
-    ///
-    /// Caught panic (if any) will be the last element of the resulting stream.
-    ///
-    /// In general, panics within a stream can propagate all the way out to the
-    /// task level. This combinator makes it possible to halt unwinding within
-    /// the stream itself. It's most commonly used within task executors. This
-    /// method should not be used for error handling.
+    /// Groups runs of consecutive items with equal keys, as computed by `f`.
     ///
-    /// Note that this method requires the `UnwindSafe` bound from the standard
-    /// library. This isn't always applied automatically, and the standard
-    /// library provides an `AssertUnwindSafe` wrapper type to apply it
-    /// after-the fact. To assist using this method, the [`Stream`] trait is
-    /// also implemented for `AssertUnwindSafe<St>` where `St` implements
-    /// [`Stream`].
+    /// The returned stream yields the key of each run together with a
+    /// [`Group`] stream of its items. Groups can be read while the stream of
+    /// groups is advanced: the remaining items of a group are buffered, and
+    /// once `capacity` items are buffered for the current group, the stream
+    /// of groups waits for the group to be read. The items of a group which
+    /// has been dropped are discarded.
     ///
     /// This method is only available when the `std` feature of this
     /// library is activated, and it is activated by default.
     ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    ///
     /// # Examples
     ///
     /// ```
@@ -863,26 +1667,27 @@ pub trait StreamExt: Stream {
     /// # futures::executor::block_on(async {
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let stream = stream::iter(vec![Some(10), None, Some(11)]);
-    /// // Panic on second element
-    /// let stream_panicking = stream.map(|o| o.unwrap());
-    /// // Collect all the results
-    /// let stream = stream_panicking.catch_unwind();
+    /// let words = stream::iter(vec!["apple", "avocado", "banana", "cherry", "cranberry"]);
+    /// let mut groups = words.group_by(8, |word| word.chars().next());
     ///
-    /// let results: Vec<Result<i32, _>> = stream.collect().await;
-    /// match results[0] {
-    ///     Ok(10) => {}
-    ///     _ => panic!("unexpected result!"),
+    /// let mut runs = Vec::new();
+    /// while let Some((key, group)) = groups.next().await {
+    ///     runs.push((key, group.collect::<Vec<_>>().await));
     /// }
-    /// assert!(results[1].is_err());
-    /// assert_eq!(results.len(), 2);
+    /// assert_eq!(runs, vec![
+    ///     (Some('a'), vec!["apple", "avocado"]),
+    ///     (Some('b'), vec!["banana"]),
+    ///     (Some('c'), vec!["cherry", "cranberry"]),
+    /// ]);
     /// # });
     /// ```
     #[cfg(feature = "std")]
-    fn catch_unwind(self) -> CatchUnwind<Self>
-        where Self: Sized + std::panic::UnwindSafe
+    fn group_by<K, F>(self, capacity: usize, f: F) -> GroupBy<Self, K, F>
+        where K: PartialEq + Clone,
+              F: FnMut(&Self::Item) -> K,
+              Self: Sized,
     {
-        CatchUnwind::new(self)
+        GroupBy::new(self, capacity, f)
     }
 
     /// Wrap the stream in a Box, pinning it.
@@ -920,6 +1725,27 @@ pub trait StreamExt: Stream {
         Buffered::new(self, n)
     }
 
+    /// An adaptor for creating a buffered list of pending futures, with a
+    /// concurrency limit which can change at runtime.
+    ///
+    /// This is like [`StreamExt::buffered`], except that the number of
+    /// futures buffered is capped by the current value of the shared `limit`.
+    /// See [`ConcurrencyLimit`] for how the limit can be changed.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "std")]
+    fn buffered_with_limit(self, limit: ConcurrencyLimit) -> Buffered<Self>
+        where Self::Item: Future,
+              Self: Sized
+    {
+        Buffered::with_limit(self, limit)
+    }
+
     /// An adaptor for creating a buffered list of pending futures (unordered).
     ///
     /// If this stream's item can be converted into a future, then this adaptor
@@ -968,6 +1794,61 @@ pub trait StreamExt: Stream {
         BufferUnordered::new(self, n)
     }
 
+    /// An adaptor for creating a buffered list of pending futures (unordered),
+    /// with a concurrency limit which can change at runtime.
+    ///
+    /// This is like [`StreamExt::buffer_unordered`], except that the number
+    /// of futures buffered is capped by the current value of the shared
+    /// `limit`. The limit can be [set](ConcurrencyLimit::set) from the outside
+    /// or be adjusted by an [`AimdController`] fed with the outcome of the
+    /// futures.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, AimdController, ConcurrencyLimit, StreamExt};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let limit = ConcurrencyLimit::new(4);
+    /// let controller = AimdController::new(limit.clone(), 1, 16)
+    ///     .latency_threshold(Duration::from_secs(1));
+    ///
+    /// let requests = stream::iter(0..10).map(|i| {
+    ///     let controller = controller.clone();
+    ///     async move {
+    ///         let start = Instant::now();
+    ///         let response = future::ready(Ok::<_, ()>(i * 2)).await;
+    ///         match response {
+    ///             Ok(_) => controller.on_success(start.elapsed()),
+    ///             Err(_) => controller.on_error(start.elapsed()),
+    ///         }
+    ///         response
+    ///     }
+    /// });
+    /// let responses = requests.buffer_unordered_with_limit(limit.clone());
+    ///
+    /// assert_eq!(responses.collect::<Vec<_>>().await.len(), 10);
+    /// assert_eq!(limit.get(), 6);
+    /// # })
+    /// ```
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "std")]
+    fn buffer_unordered_with_limit(self, limit: ConcurrencyLimit) -> BufferUnordered<Self>
+        where Self::Item: Future,
+              Self: Sized
+    {
+        BufferUnordered::with_limit(self, limit)
+    }
+
     /// An adapter for zipping two streams together.
     ///
     /// The zipped stream waits for both streams to produce an item, and then
@@ -1038,6 +1919,80 @@ pub trait StreamExt: Stream {
         Peekable::new(self)
     }
 
+    /// Repeats a stream endlessly.
+    ///
+    /// The stream is cloned before it is first polled, and once it has ended,
+    /// it is restarted from that copy. If the stream doesn't yield any items,
+    /// the returned stream ends instead of restarting it forever.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 2]).cycle().take(5);
+    ///
+    /// assert_eq!(vec![1, 2, 1, 2, 1], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn cycle(self) -> Cycle<Self>
+        where Self: Sized + Clone
+    {
+        Cycle::new(self)
+    }
+
+    /// Creates a stream starting at the same point, but stepping by the given
+    /// amount at each iteration.
+    ///
+    /// The first item of the underlying stream is always yielded, and then
+    /// every `step`-th item after it, like `Iterator::step_by`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(0..=10).step_by(4);
+    ///
+    /// assert_eq!(vec![0, 4, 8], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn step_by(self, step: usize) -> StepBy<Self>
+        where Self: Sized
+    {
+        StepBy::new(self, step)
+    }
+
+    /// Places a copy of `separator` between each pair of adjacent items of
+    /// this stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!["a", "b", "c"]).intersperse(",");
+    ///
+    /// assert_eq!(vec!["a", ",", "b", ",", "c"], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn intersperse(self, separator: Self::Item) -> Intersperse<Self>
+        where Self::Item: Clone,
+              Self: Sized
+    {
+        Intersperse::new(self, separator)
+    }
+
     /// An adaptor for chunking up items of the stream inside a vector.
     ///
     /// This combinator will attempt to pull items from this stream and buffer
@@ -1063,6 +2018,198 @@ pub trait StreamExt: Stream {
         Chunks::new(self, capacity)
     }
 
+    /// An adaptor for chunking up the items of the stream which are ready
+    /// inside a vector.
+    ///
+    /// Unlike [`StreamExt::chunks`], this combinator never waits to fill up a
+    /// chunk. Once the first item of a chunk is available, it keeps pulling
+    /// items from this stream until it returns `Poll::Pending` or `capacity`
+    /// items have been buffered, and then yields the chunk straight away.
+    ///
+    /// If the underlying stream ended, the items buffered so far are yielded
+    /// before the returned stream ends as well.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::stream::StreamExt;
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    /// let mut stream = rx.ready_chunks(2);
+    ///
+    /// tx.unbounded_send(1).unwrap();
+    /// assert_eq!(stream.next().await, Some(vec![1]));
+    ///
+    /// tx.unbounded_send(2).unwrap();
+    /// tx.unbounded_send(3).unwrap();
+    /// tx.unbounded_send(4).unwrap();
+    /// assert_eq!(stream.next().await, Some(vec![2, 3]));
+    ///
+    /// drop(tx);
+    /// assert_eq!(stream.next().await, Some(vec![4]));
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "alloc")]
+    fn ready_chunks(self, capacity: usize) -> ReadyChunks<Self>
+        where Self: Sized
+    {
+        ReadyChunks::new(self, capacity)
+    }
+
+    /// An adaptor for iterating over overlapping windows of `size` items of
+    /// the stream.
+    ///
+    /// Once `size` items have been pulled from this stream, every further
+    /// item yields a vector of the latest `size` items. No window is yielded
+    /// if the stream ends before that.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=4).windows(3);
+    ///
+    /// assert_eq!(vec![vec![1, 2, 3], vec![2, 3, 4]], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `size` is zero.
+    #[cfg(feature = "alloc")]
+    fn windows(self, size: usize) -> Windows<Self>
+        where Self::Item: Clone,
+              Self: Sized
+    {
+        Windows::new(self, size)
+    }
+
+    /// An adaptor for iterating over pairs of consecutive items of the stream.
+    ///
+    /// Every item but the first one is yielded together with the item before
+    /// it, which is why the items need to be `Clone`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(1..=3).pairwise();
+    ///
+    /// assert_eq!(vec![(1, 2), (2, 3)], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn pairwise(self) -> Pairwise<Self>
+        where Self::Item: Clone,
+              Self: Sized
+    {
+        Pairwise::new(self)
+    }
+
+    /// Removes consecutive repeated items of the stream.
+    ///
+    /// An item is skipped if it is equal to the item yielded last, so only
+    /// runs of duplicates are collapsed. A copy of the last item is kept to
+    /// compare the next one against.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 1, 2, 3, 3, 1]).dedup();
+    ///
+    /// assert_eq!(vec![1, 2, 3, 1], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn dedup(self) -> Dedup<Self>
+        where Self::Item: PartialEq + Clone,
+              Self: Sized
+    {
+        Dedup::new(self)
+    }
+
+    /// Removes consecutive items of the stream which resolve to the same key.
+    ///
+    /// Like [`StreamExt::dedup`], but items are compared by the key the
+    /// closure returns for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![10, 11, 20, 12]).dedup_by_key(|x| x / 10);
+    ///
+    /// assert_eq!(vec![10, 20, 12], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn dedup_by_key<K, F>(self, f: F) -> DedupByKey<Self, K, F>
+        where F: FnMut(&Self::Item) -> K,
+              K: PartialEq,
+              Self: Sized
+    {
+        DedupByKey::new(self, f)
+    }
+
+    /// Removes items of the stream whose key has been seen recently.
+    ///
+    /// The keys of the last `capacity` distinct items are remembered, and an
+    /// item is skipped if its key is among them. Seeing a key again makes it
+    /// the most recent one, and once the set is full the least recently seen
+    /// key is forgotten to make room for a new one.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 2, 1, 3, 1, 2]).distinct_by_key(2, |x| *x);
+    ///
+    /// assert_eq!(vec![1, 2, 3, 2], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "std")]
+    fn distinct_by_key<K, F>(self, capacity: usize, f: F) -> DistinctByKey<Self, K, F>
+        where F: FnMut(&Self::Item) -> K,
+              K: Eq + Hash + Clone,
+              Self: Sized
+    {
+        DistinctByKey::new(self, capacity, f)
+    }
+
     /// A future that completes after the given stream has been fully processed
     /// into the sink and the sink has been flushed and closed.
     ///
@@ -0,0 +1,109 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`pairwise`](super::StreamExt::pairwise) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Pairwise<St: Stream> {
+    stream: St,
+    prev: Option<St::Item>,
+}
+
+impl<St: Stream + Unpin> Unpin for Pairwise<St> {}
+
+impl<St> Pairwise<St>
+    where St: Stream,
+          St::Item: Clone,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(prev: Option<St::Item>);
+
+    pub(super) fn new(stream: St) -> Pairwise<St> {
+        Pairwise { stream, prev: None }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut St> {
+        self.stream()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St> FusedStream for Pairwise<St>
+    where St: Stream + FusedStream,
+          St::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St> Stream for Pairwise<St>
+    where St: Stream,
+          St::Item: Clone,
+{
+    type Item = (St::Item, St::Item);
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            let item = match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => item,
+                None => return Poll::Ready(None),
+            };
+            let prev = self.as_mut().prev().replace(item.clone());
+            if let Some(prev) = prev {
+                return Poll::Ready(Some((prev, item)));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The first item only starts the first pair.
+        let pairs = |n: usize| if self.prev.is_some() { n } else { n.saturating_sub(1) };
+
+        let (lower, upper) = self.stream.size_hint();
+        (pairs(lower), upper.map(pairs))
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Item> Sink<Item> for Pairwise<S>
+    where S: Stream + Sink<Item>,
+          S::Item: Clone,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
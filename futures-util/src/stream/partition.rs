@@ -0,0 +1,103 @@
+use super::fork::poll_half;
+use super::shared::Notifier;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// Stream for the [`partition`](super::StreamExt::partition) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct Partition<St: Stream, F> {
+    inner: Arc<Inner<St, F>>,
+    // 0 for the items matching the predicate, 1 for the others.
+    side: usize,
+}
+
+struct Inner<St: Stream, F> {
+    state: Mutex<State<St, F>>,
+    notifier: Arc<Notifier>,
+}
+
+struct State<St: Stream, F> {
+    // `None` once the stream has ended.
+    stream: Option<Pin<Box<St>>>,
+    f: F,
+    buffers: [VecDeque<St::Item>; 2],
+    dropped: [bool; 2],
+    capacity: usize,
+}
+
+// The stream itself is polled behind the `Arc`, so it won't be moved
+// when `Partition` is moved.
+impl<St: Stream, F> Unpin for Partition<St, F> {}
+
+impl<St: Stream, F> fmt::Debug for Partition<St, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Partition")
+            .field("side", &self.side)
+            .finish()
+    }
+}
+
+impl<St, F> Partition<St, F>
+    where St: Stream,
+          F: FnMut(&St::Item) -> bool,
+{
+    pub(super) fn new(stream: St, capacity: usize, f: F) -> (Self, Self) {
+        assert!(capacity > 0);
+
+        let inner = Arc::new(Inner {
+            state: Mutex::new(State {
+                stream: Some(Box::pin(stream)),
+                f,
+                buffers: [VecDeque::new(), VecDeque::new()],
+                dropped: [false, false],
+                capacity,
+            }),
+            notifier: Arc::new(Notifier::new()),
+        });
+        (
+            Partition { inner: inner.clone(), side: 0 },
+            Partition { inner, side: 1 },
+        )
+    }
+}
+
+impl<St, F> Stream for Partition<St, F>
+    where St: Stream,
+          F: FnMut(&St::Item) -> bool,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let inner = &*self.inner;
+        let mut state = inner.state.lock().unwrap();
+        let State { stream, f, buffers, dropped, capacity } = &mut *state;
+        let matching = self.side == 0;
+        let [first, second] = buffers;
+        let (own, other) = if matching { (first, second) } else { (second, first) };
+        let other = if dropped[1 - self.side] { None } else { Some(other) };
+
+        poll_half(stream, own, other, *capacity, &inner.notifier, cx, |item| {
+            if f(&item) == matching {
+                (Some(item), None)
+            } else {
+                (None, Some(item))
+            }
+        })
+    }
+}
+
+impl<St: Stream, F> Drop for Partition<St, F> {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.inner.state.lock() {
+            // Items for this half are discarded from now on.
+            state.dropped[self.side] = true;
+            state.buffers[self.side].clear();
+            // The other half may have been waiting for this one.
+            self.inner.notifier.wake_all();
+        }
+    }
+}
@@ -18,10 +18,20 @@ pub fn pending<T>() -> Pending<T> {
     Pending { _data: marker::PhantomData }
 }
 
+impl<T> Clone for Pending<T> {
+    fn clone(&self) -> Self {
+        pending()
+    }
+}
+
 impl<T> Stream for Pending<T> {
     type Item = T;
 
     fn poll_next(self: Pin<&mut Self>, _: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
         Poll::Pending
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
 }
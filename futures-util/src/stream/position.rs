@@ -0,0 +1,89 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`position`](super::StreamExt::position) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Position<St, Fut, F> {
+    stream: St,
+    f: F,
+    // The index of the item which is currently being checked, if any.
+    index: usize,
+    future: Option<Fut>,
+    done: bool,
+}
+
+impl<St: Unpin, Fut: Unpin, F> Unpin for Position<St, Fut, F> {}
+
+impl<St, Fut, F> fmt::Debug for Position<St, Fut, F>
+where
+    St: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Position")
+            .field("stream", &self.stream)
+            .field("index", &self.index)
+            .field("future", &self.future)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> Position<St, Fut, F>
+    where St: Stream,
+          F: FnMut(St::Item) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(index: usize);
+    unsafe_pinned!(future: Option<Fut>);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, f: F) -> Position<St, Fut, F> {
+        Position { stream, f, index: 0, future: None, done: false }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for Position<St, Fut, F> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St, Fut, F> Future for Position<St, Fut, F>
+    where St: Stream,
+          F: FnMut(St::Item) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    type Output = Option<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<usize>> {
+        loop {
+            if let Some(future) = self.as_mut().future().as_pin_mut() {
+                let found = ready!(future.poll(cx));
+                self.as_mut().future().set(None);
+                if found {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Some(self.index));
+                }
+                *self.as_mut().index() += 1;
+            }
+
+            match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => {
+                    let future = (self.as_mut().f())(item);
+                    self.as_mut().future().set(Some(future));
+                }
+                None => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::stream::Fuse;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::unsafe_pinned;
+use core::pin::Pin;
+use alloc::vec::Vec;
+
+/// Stream for the [`ready_chunks`](super::StreamExt::ready_chunks) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ReadyChunks<St> {
+    stream: Fuse<St>,
+    cap: usize,
+}
+
+impl<St: Unpin> Unpin for ReadyChunks<St> {}
+
+impl<St: Stream> ReadyChunks<St> {
+    unsafe_pinned!(stream: Fuse<St>);
+
+    pub(super) fn new(stream: St, capacity: usize) -> ReadyChunks<St> {
+        assert!(capacity > 0);
+
+        ReadyChunks {
+            stream: super::Fuse::new(stream),
+            cap: capacity,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut St> {
+        self.stream().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St: Stream> Stream for ReadyChunks<St> {
+    type Item = Vec<St::Item>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let mut items = Vec::new();
+
+        loop {
+            match self.as_mut().stream().poll_next(cx) {
+                // Only wait for the first item of a chunk, afterwards yield
+                // whatever is ready.
+                Poll::Pending => {
+                    return if items.is_empty() {
+                        Poll::Pending
+                    } else {
+                        Poll::Ready(Some(items))
+                    };
+                }
+
+                Poll::Ready(Some(item)) => {
+                    if items.is_empty() {
+                        items.reserve(self.cap);
+                    }
+                    items.push(item);
+                    if items.len() >= self.cap {
+                        return Poll::Ready(Some(items));
+                    }
+                }
+
+                // Since the underlying stream ran out of values, return what we
+                // have buffered, if we have anything.
+                Poll::Ready(None) => {
+                    let last = if items.is_empty() {
+                        None
+                    } else {
+                        Some(items)
+                    };
+
+                    return Poll::Ready(last);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Chunks can be as small as a single item.
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(self.cap - 1) / self.cap;
+        (lower, upper)
+    }
+}
+
+impl<St: Stream> FusedStream for ReadyChunks<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Item> Sink<Item> for ReadyChunks<S>
+where
+    S: Stream + Sink<Item>,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,99 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`reduce`](super::StreamExt::reduce) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Reduce<St, Fut, F>
+    where St: Stream,
+{
+    stream: St,
+    f: F,
+    accum: Option<St::Item>,
+    future: Option<Fut>,
+}
+
+impl<St, Fut, F> Unpin for Reduce<St, Fut, F>
+where
+    St: Stream + Unpin,
+    Fut: Unpin,
+{}
+
+impl<St, Fut, F> fmt::Debug for Reduce<St, Fut, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reduce")
+            .field("stream", &self.stream)
+            .field("accum", &self.accum)
+            .field("future", &self.future)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> Reduce<St, Fut, F>
+    where St: Stream,
+          F: FnMut(St::Item, St::Item) -> Fut,
+          Fut: Future<Output = St::Item>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(accum: Option<St::Item>);
+    unsafe_pinned!(future: Option<Fut>);
+
+    pub(super) fn new(stream: St, f: F) -> Reduce<St, Fut, F> {
+        Reduce {
+            stream,
+            f,
+            accum: None,
+            future: None,
+        }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for Reduce<St, Fut, F>
+    where St: Stream + FusedStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.future.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St, Fut, F> Future for Reduce<St, Fut, F>
+    where St: Stream,
+          F: FnMut(St::Item, St::Item) -> Fut,
+          Fut: Future<Output = St::Item>,
+{
+    type Output = Option<St::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            // we're currently processing a future to produce a new accum value
+            if let Some(future) = self.as_mut().future().as_pin_mut() {
+                let accum = ready!(future.poll(cx));
+                *self.as_mut().accum() = Some(accum);
+                self.as_mut().future().set(None);
+            }
+
+            let item = match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => item,
+                None => return Poll::Ready(self.as_mut().accum().take()),
+            };
+
+            // The first item is the initial accum value.
+            match self.as_mut().accum().take() {
+                Some(accum) => {
+                    let future = (self.as_mut().f())(accum, item);
+                    self.as_mut().future().set(Some(future));
+                }
+                None => *self.as_mut().accum() = Some(item),
+            }
+        }
+    }
+}
@@ -3,7 +3,7 @@ use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
 
 /// Stream for the [`repeat`] function.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[must_use = "streams do nothing unless polled"]
 pub struct Repeat<T> {
     item: T,
@@ -40,4 +40,8 @@ impl<T> Stream for Repeat<T>
     fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         Poll::Ready(Some(self.item.clone()))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::max_value(), None)
+    }
 }
@@ -0,0 +1,158 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`scan`](super::StreamExt::scan) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct Scan<St, S, Fut, F> {
+    stream: St,
+    state: S,
+    f: F,
+    future: Option<Fut>,
+    done_taking: bool,
+}
+
+impl<St: Unpin, S, Fut: Unpin, F> Unpin for Scan<St, S, Fut, F> {}
+
+impl<St, S, Fut, F> fmt::Debug for Scan<St, S, Fut, F>
+where
+    St: fmt::Debug,
+    S: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scan")
+            .field("stream", &self.stream)
+            .field("state", &self.state)
+            .field("future", &self.future)
+            .field("done_taking", &self.done_taking)
+            .finish()
+    }
+}
+
+impl<St, S, Fut, F, B> Scan<St, S, Fut, F>
+    where St: Stream,
+          F: FnMut(&mut S, St::Item) -> Fut,
+          Fut: Future<Output = Option<B>>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_pinned!(future: Option<Fut>);
+    unsafe_unpinned!(done_taking: bool);
+
+    pub(super) fn new(stream: St, initial_state: S, f: F) -> Scan<St, S, Fut, F> {
+        Scan {
+            stream,
+            state: initial_state,
+            f,
+            future: None,
+            done_taking: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut St> {
+        self.stream()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, S, Fut, F, B> Stream for Scan<St, S, Fut, F>
+    where St: Stream,
+          F: FnMut(&mut S, St::Item) -> Fut,
+          Fut: Future<Output = Option<B>>,
+{
+    type Item = B;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<B>> {
+        if self.done_taking {
+            return Poll::Ready(None);
+        }
+
+        if self.future.is_none() {
+            let item = match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(e) => e,
+                None => return Poll::Ready(None),
+            };
+            // The closure and its state are never pinned.
+            let this = unsafe { self.as_mut().get_unchecked_mut() };
+            let fut = (this.f)(&mut this.state, item);
+            self.as_mut().future().set(Some(fut));
+        }
+
+        let item = ready!(self.as_mut().future().as_pin_mut().unwrap().poll(cx));
+        self.as_mut().future().set(None);
+
+        if item.is_none() {
+            *self.as_mut().done_taking() = true;
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done_taking {
+            return (0, Some(0));
+        }
+
+        let pending_len = if self.future.is_some() { 1 } else { 0 };
+        let (_, upper) = self.stream.size_hint();
+        let upper = match upper {
+            Some(x) => x.checked_add(pending_len),
+            None => None,
+        };
+        (0, upper) // can't know a lower bound, due to the closure
+    }
+}
+
+impl<St, S, Fut, F, B> FusedStream for Scan<St, S, Fut, F>
+    where St: Stream + FusedStream,
+          F: FnMut(&mut S, St::Item) -> Fut,
+          Fut: Future<Output = Option<B>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done_taking || (self.future.is_none() && self.stream.is_terminated())
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<St, S, Fut, F, B, Item> Sink<Item> for Scan<St, S, Fut, F>
+    where St: Stream + Sink<Item>,
+          F: FnMut(&mut S, St::Item) -> Fut,
+          Fut: Future<Output = Option<B>>,
+{
+    type SinkError = St::SinkError;
+
+    delegate_sink!(stream, Item);
+}
@@ -99,6 +99,20 @@ impl<St1, St2> Stream for Select<St1, St2>
             poll_inner(flag, stream2, stream1, cx)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (stream1_lower, stream1_upper) = self.stream1.size_hint();
+        let (stream2_lower, stream2_upper) = self.stream2.size_hint();
+
+        let lower = stream1_lower.saturating_add(stream2_lower);
+
+        let upper = match (stream1_upper, stream2_upper) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None
+        };
+
+        (lower, upper)
+    }
 }
 
 fn poll_inner<St1, St2>(
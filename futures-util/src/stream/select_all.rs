@@ -1,5 +1,6 @@
 //! An unbounded set of streams
 
+use alloc::vec::Vec;
 use core::fmt::{self, Debug};
 use core::iter::FromIterator;
 use core::pin::Pin;
@@ -18,12 +19,19 @@ use crate::stream::{StreamExt, StreamFuture, FuturesUnordered};
 /// yielded as they become ready. Streams will only be polled when they
 /// generate notifications. This allows to coordinate a large number of streams.
 ///
+/// Streams can be pushed with a priority using
+/// [`push_with_priority`](SelectAll::push_with_priority). Streams with a
+/// higher priority always go first: the items of a lower priority stream are
+/// only yielded while no stream with a higher priority is ready.
+///
 /// Note that you can create a ready-made `SelectAll` via the
 /// `select_all` function in the `stream` module, or you can start with an
 /// empty set with the `SelectAll::new` constructor.
 #[must_use = "streams do nothing unless polled"]
 pub struct SelectAll<St> {
-    inner: FuturesUnordered<StreamFuture<St>>,
+    // Sorted by descending priority, always containing the tier of priority
+    // 0 used by `push`.
+    tiers: Vec<(usize, FuturesUnordered<StreamFuture<St>>)>,
 }
 
 impl<St: Debug> Debug for SelectAll<St> {
@@ -38,19 +46,19 @@ impl<St: Stream + Unpin> SelectAll<St> {
     /// The returned `SelectAll` does not contain any streams and, in this
     /// state, `SelectAll::poll` will return `Poll::Ready(None)`.
     pub fn new() -> SelectAll<St> {
-        SelectAll { inner: FuturesUnordered::new() }
+        SelectAll { tiers: vec![(0, FuturesUnordered::new())] }
     }
 
     /// Returns the number of streams contained in the set.
     ///
     /// This represents the total number of in-flight streams.
     pub fn len(&self) -> usize {
-        self.inner.len()
+        self.tiers.iter().map(|(_, tier)| tier.len()).sum()
     }
 
     /// Returns `true` if the set contains no streams
     pub fn is_empty(&self) -> bool {
-        self.inner.is_empty()
+        self.tiers.iter().all(|(_, tier)| tier.is_empty())
     }
 
     /// Push a stream into the set.
@@ -59,8 +67,41 @@ impl<St: Stream + Unpin> SelectAll<St> {
     /// function will not call `poll` on the submitted stream. The caller must
     /// ensure that `SelectAll::poll` is called in order to receive task
     /// notifications.
+    ///
+    /// The stream is given priority 0, the lowest priority.
     pub fn push(&mut self, stream: St) {
-        self.inner.push(stream.into_future());
+        self.push_with_priority(stream, 0);
+    }
+
+    /// Push a stream into the set with the given priority.
+    ///
+    /// Whenever a stream with a higher priority is ready to yield an item,
+    /// that item is yielded before those of streams with a lower priority.
+    /// Streams with the same priority are treated fairly among each other.
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, SelectAll, StreamExt};
+    ///
+    /// let mut set = SelectAll::new();
+    /// set.push(stream::iter(vec!["data"]));
+    /// set.push_with_priority(stream::iter(vec!["control"]), 1);
+    ///
+    /// assert_eq!(set.collect::<Vec<_>>().await, vec!["control", "data"]);
+    /// # });
+    /// ```
+    pub fn push_with_priority(&mut self, stream: St, priority: usize) {
+        let idx = match self.tiers.iter().position(|&(p, _)| p <= priority) {
+            Some(idx) if self.tiers[idx].0 == priority => idx,
+            Some(idx) => {
+                self.tiers.insert(idx, (priority, FuturesUnordered::new()));
+                idx
+            }
+            // The tier of priority 0 is always last.
+            None => unreachable!(),
+        };
+        self.tiers[idx].1.push(stream.into_future());
     }
 }
 
@@ -77,27 +118,69 @@ impl<St: Stream + Unpin> Stream for SelectAll<St> {
         mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<Option<Self::Item>> {
-        loop {
-            match ready!(self.inner.poll_next_unpin(cx)) {
-                Some((Some(item), remaining)) => {
-                    self.push(remaining);
-                    return Poll::Ready(Some(item));
-                }
-                Some((None, _)) => {
-                    // `FuturesUnordered` thinks it isn't terminated
-                    // because it yielded a Some.
-                    // We do not return, but poll `FuturesUnordered`
-                    // in the next loop iteration.
+        let mut pending = false;
+        let mut idx = 0;
+        while idx < self.tiers.len() {
+            let mut exhausted = false;
+            let (priority, ref mut tier) = self.tiers[idx];
+            loop {
+                match tier.poll_next_unpin(cx) {
+                    Poll::Ready(Some((Some(item), remaining))) => {
+                        tier.push(remaining.into_future());
+                        return Poll::Ready(Some(item));
+                    }
+                    Poll::Ready(Some((None, _))) => {
+                        // `FuturesUnordered` thinks it isn't terminated
+                        // because it yielded a Some.
+                        // We do not return, but poll `FuturesUnordered`
+                        // in the next loop iteration.
+                    }
+                    Poll::Ready(None) => {
+                        exhausted = true;
+                        break;
+                    }
+                    Poll::Pending => {
+                        pending = true;
+                        break;
+                    }
                 }
-                None => return Poll::Ready(None),
             }
+            // Only the tier of priority 0 is kept once all of its streams
+            // have ended.
+            if exhausted && priority != 0 {
+                drop(self.tiers.remove(idx));
+            } else {
+                idx += 1;
+            }
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(None)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut lower = 0usize;
+        let mut upper = Some(0usize);
+        let streams = self.tiers.iter()
+            .flat_map(|(_, tier)| tier.iter())
+            .filter_map(|fut| fut.get_ref());
+        for stream in streams {
+            let (stream_lower, stream_upper) = stream.size_hint();
+            lower = lower.saturating_add(stream_lower);
+            upper = match (upper, stream_upper) {
+                (Some(x), Some(y)) => x.checked_add(y),
+                _ => None,
+            };
         }
+        (lower, upper)
     }
 }
 
 impl<St: Stream + Unpin> FusedStream for SelectAll<St> {
     fn is_terminated(&self) -> bool {
-        self.inner.is_terminated()
+        self.tiers.iter().all(|(_, tier)| tier.is_terminated())
     }
 }
 
@@ -0,0 +1,216 @@
+use crate::stream::{StreamExt, Fuse};
+use core::fmt;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+
+/// The stream which [`select_with_strategy`] should poll first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PollNext {
+    /// Poll the first stream.
+    Left,
+    /// Poll the second stream.
+    Right,
+}
+
+impl PollNext {
+    /// Toggles the value, returning the previous one.
+    ///
+    /// This is useful for keeping round-robin state in a strategy.
+    pub fn toggle(&mut self) -> PollNext {
+        let old = *self;
+        *self = match old {
+            PollNext::Left => PollNext::Right,
+            PollNext::Right => PollNext::Left,
+        };
+        old
+    }
+}
+
+#[allow(clippy::derivable_impls)] // `#[default]` on variants needs a newer compiler
+impl Default for PollNext {
+    fn default() -> Self {
+        PollNext::Left
+    }
+}
+
+/// Stream for the [`select_with_strategy()`] function.
+#[must_use = "streams do nothing unless polled"]
+pub struct SelectWithStrategy<St1, St2, Clos, State> {
+    stream1: Fuse<St1>,
+    stream2: Fuse<St2>,
+    state: State,
+    clos: Clos,
+}
+
+impl<St1: Unpin, St2: Unpin, Clos, State> Unpin for SelectWithStrategy<St1, St2, Clos, State> {}
+
+impl<St1, St2, Clos, State> fmt::Debug for SelectWithStrategy<St1, St2, Clos, State>
+    where St1: fmt::Debug,
+          St2: fmt::Debug,
+          State: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SelectWithStrategy")
+            .field("stream1", &self.stream1)
+            .field("stream2", &self.stream2)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+/// This function will attempt to pull items from both streams, letting a
+/// strategy decide which of them is polled first.
+///
+/// Before each poll, the closure `which_first` is called with a mutable
+/// reference to its state, which starts out as `State::default()`. The
+/// stream it returns is polled first, and the other one is only polled if
+/// the first one isn't ready to yield an item. Unlike [`select()`], which
+/// alternates between the two streams, this allows one stream to be
+/// preferred over the other.
+///
+/// After one of the two input stream completes, the remaining one will be
+/// polled exclusively. The returned stream completes when both input
+/// streams have completed.
+///
+/// # Examples
+///
+/// A biased strategy, which always prefers the first stream:
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, PollNext, StreamExt};
+///
+/// let control = stream::iter(vec!["stop", "start"]);
+/// let data = stream::iter(vec!["a", "b"]);
+/// let stream = stream::select_with_strategy(control, data, |_: &mut ()| PollNext::Left);
+///
+/// assert_eq!(stream.collect::<Vec<_>>().await, vec!["stop", "start", "a", "b"]);
+/// # });
+/// ```
+///
+/// A weighted strategy, which polls the first stream first three times as
+/// often as the second one:
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, PollNext, StreamExt};
+///
+/// let left = stream::repeat('l');
+/// let right = stream::repeat('r');
+/// let stream = stream::select_with_strategy(left, right, |polls: &mut u32| {
+///     *polls = (*polls + 1) % 4;
+///     if *polls == 0 { PollNext::Right } else { PollNext::Left }
+/// });
+///
+/// let items = stream.take(8).collect::<String>().await;
+/// assert_eq!(items, "lllrlllr");
+/// # });
+/// ```
+pub fn select_with_strategy<St1, St2, Clos, State>(
+    stream1: St1,
+    stream2: St2,
+    which_first: Clos,
+) -> SelectWithStrategy<St1, St2, Clos, State>
+    where St1: Stream,
+          St2: Stream<Item = St1::Item>,
+          Clos: FnMut(&mut State) -> PollNext,
+          State: Default,
+{
+    SelectWithStrategy {
+        stream1: stream1.fuse(),
+        stream2: stream2.fuse(),
+        state: State::default(),
+        clos: which_first,
+    }
+}
+
+impl<St1, St2, Clos, State> SelectWithStrategy<St1, St2, Clos, State> {
+    /// Acquires a reference to the underlying streams that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> (&St1, &St2) {
+        (self.stream1.get_ref(), self.stream2.get_ref())
+    }
+
+    /// Acquires a mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> (&mut St1, &mut St2) {
+        (self.stream1.get_mut(), self.stream2.get_mut())
+    }
+
+    /// Consumes this combinator, returning the underlying streams.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> (St1, St2) {
+        (self.stream1.into_inner(), self.stream2.into_inner())
+    }
+}
+
+impl<St1, St2, Clos, State> FusedStream for SelectWithStrategy<St1, St2, Clos, State> {
+    fn is_terminated(&self) -> bool {
+        self.stream1.is_terminated() && self.stream2.is_terminated()
+    }
+}
+
+impl<St1, St2, Clos, State> Stream for SelectWithStrategy<St1, St2, Clos, State>
+    where St1: Stream,
+          St2: Stream<Item = St1::Item>,
+          Clos: FnMut(&mut State) -> PollNext,
+{
+    type Item = St1::Item;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St1::Item>> {
+        let SelectWithStrategy { stream1, stream2, state, clos } =
+            unsafe { self.get_unchecked_mut() };
+        let stream1 = unsafe { Pin::new_unchecked(stream1) };
+        let stream2 = unsafe { Pin::new_unchecked(stream2) };
+
+        match clos(state) {
+            PollNext::Left => poll_inner(stream1, stream2, cx),
+            PollNext::Right => poll_inner(stream2, stream1, cx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (stream1_lower, stream1_upper) = self.stream1.size_hint();
+        let (stream2_lower, stream2_upper) = self.stream2.size_hint();
+
+        let lower = stream1_lower.saturating_add(stream2_lower);
+
+        let upper = match (stream1_upper, stream2_upper) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None
+        };
+
+        (lower, upper)
+    }
+}
+
+fn poll_inner<St1, St2>(
+    a: Pin<&mut Fuse<St1>>,
+    b: Pin<&mut Fuse<St2>>,
+    cx: &mut Context<'_>
+) -> Poll<Option<St1::Item>>
+    where St1: Stream, St2: Stream<Item = St1::Item>
+{
+    let a_done = match a.poll_next(cx) {
+        Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+        Poll::Ready(None) => true,
+        Poll::Pending => false,
+    };
+
+    match b.poll_next(cx) {
+        Poll::Ready(Some(item)) => Poll::Ready(Some(item)),
+        Poll::Ready(None) if a_done => Poll::Ready(None),
+        Poll::Ready(None) | Poll::Pending => Poll::Pending,
+    }
+}
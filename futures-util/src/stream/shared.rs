@@ -0,0 +1,242 @@
+use crate::task::{ArcWake, waker_ref};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll, Waker};
+use slab::Slab;
+use std::collections::VecDeque;
+use std::fmt;
+use std::mem;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// Stream for the [`shared`](super::StreamExt::shared) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct Shared<St: Stream> {
+    inner: Arc<Inner<St>>,
+    // The key of this handle's position in `State::positions`.
+    key: usize,
+    done: bool,
+}
+
+struct Inner<St: Stream> {
+    state: Mutex<State<St>>,
+    notifier: Arc<Notifier>,
+}
+
+struct State<St: Stream> {
+    // `None` once the stream has ended.
+    stream: Option<Pin<Box<St>>>,
+    // Items which haven't been yielded by all handles yet.
+    buffer: VecDeque<St::Item>,
+    // The index of the first item in `buffer`.
+    start: usize,
+    capacity: usize,
+    // The index of the next item of each handle.
+    positions: Slab<usize>,
+}
+
+// Wakes all handles waiting for the stream or for the slowest handle.
+pub(super) struct Notifier {
+    wakers: Mutex<Vec<Waker>>,
+}
+
+// The stream itself is polled behind the `Arc`, so it won't be moved
+// when `Shared` is moved.
+impl<St: Stream> Unpin for Shared<St> {}
+
+impl<St: Stream> fmt::Debug for Shared<St> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shared")
+            .field("key", &self.key)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<St: Stream> Shared<St> {
+    pub(super) fn new(stream: St, capacity: usize) -> Shared<St> {
+        assert!(capacity > 0);
+
+        let mut positions = Slab::new();
+        let key = positions.insert(0);
+        let state = State {
+            stream: Some(Box::pin(stream)),
+            buffer: VecDeque::new(),
+            start: 0,
+            capacity,
+            positions,
+        };
+
+        Shared {
+            inner: Arc::new(Inner {
+                state: Mutex::new(state),
+                notifier: Arc::new(Notifier::new()),
+            }),
+            key,
+            done: false,
+        }
+    }
+}
+
+impl<St: Stream> State<St> {
+    // Drops the items which all handles have yielded, waking the handles
+    // waiting for space in the buffer.
+    fn trim(&mut self, notifier: &Notifier) {
+        let slowest = match self.positions.iter().map(|(_, &position)| position).min() {
+            Some(position) => position,
+            None => self.start + self.buffer.len(),
+        };
+        if slowest > self.start {
+            self.buffer.drain(..slowest - self.start);
+            self.start = slowest;
+            notifier.wake_all();
+        }
+    }
+}
+
+impl Notifier {
+    pub(super) fn new() -> Notifier {
+        Notifier {
+            wakers: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub(super) fn register(&self, waker: &Waker) {
+        let mut wakers = self.wakers.lock().unwrap();
+        if !wakers.iter().any(|w| w.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+    }
+
+    pub(super) fn wake_all(&self) {
+        let wakers = mem::replace(&mut *self.wakers.lock().unwrap(), Vec::new());
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl ArcWake for Notifier {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.wake_all();
+    }
+}
+
+impl<St> FusedStream for Shared<St>
+where
+    St: Stream,
+    St::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St> Stream for Shared<St>
+where
+    St: Stream,
+    St::Item: Clone,
+{
+    type Item = St::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        let this = &mut *self;
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        let inner = &*this.inner;
+        let mut state = inner.state.lock().unwrap();
+        let state = &mut *state;
+        let next = state.positions[this.key];
+
+        // Yield an item another handle has already pulled from the stream.
+        if next < state.start + state.buffer.len() {
+            let item = state.buffer[next - state.start].clone();
+            state.positions[this.key] = next + 1;
+            state.trim(&inner.notifier);
+            return Poll::Ready(Some(item));
+        }
+
+        let stream = match &mut state.stream {
+            Some(stream) => stream,
+            None => {
+                this.done = true;
+                return Poll::Ready(None);
+            }
+        };
+
+        // Register before checking for space or polling the stream, so that
+        // no wakeup is missed.
+        inner.notifier.register(cx.waker());
+        if state.buffer.len() >= state.capacity {
+            // Wait for the slowest handle to catch up.
+            return Poll::Pending;
+        }
+
+        let waker = waker_ref(&inner.notifier);
+        let mut stream_cx = Context::from_waker(&waker);
+        match stream.as_mut().poll_next(&mut stream_cx) {
+            Poll::Ready(Some(item)) => {
+                state.buffer.push_back(item.clone());
+                state.positions[this.key] = next + 1;
+                state.trim(&inner.notifier);
+                // The other handles may be waiting for this item.
+                inner.notifier.wake_all();
+                Poll::Ready(Some(item))
+            }
+            Poll::Ready(None) => {
+                state.stream = None;
+                this.done = true;
+                inner.notifier.wake_all();
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let state = self.inner.state.lock().unwrap();
+        let buffered = state.start + state.buffer.len() - state.positions[self.key];
+        match &state.stream {
+            Some(stream) => {
+                let (lower, upper) = stream.size_hint();
+                let lower = lower.saturating_add(buffered);
+                let upper = match upper {
+                    Some(x) => x.checked_add(buffered),
+                    None => None,
+                };
+                (lower, upper)
+            }
+            None => (buffered, Some(buffered)),
+        }
+    }
+}
+
+impl<St: Stream> Clone for Shared<St> {
+    /// Creates another handle to the stream, which yields the same items as
+    /// this one from here on.
+    fn clone(&self) -> Self {
+        let mut state = self.inner.state.lock().unwrap();
+        let position = state.positions[self.key];
+        let key = state.positions.insert(position);
+        Shared {
+            inner: self.inner.clone(),
+            key,
+            done: self.done,
+        }
+    }
+}
+
+impl<St: Stream> Drop for Shared<St> {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.inner.state.lock() {
+            state.positions.remove(self.key);
+            // The other handles may have been waiting for this one.
+            state.trim(&self.inner.notifier);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use core::cmp;
 use core::pin::Pin;
 use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Context, Poll};
@@ -80,6 +81,16 @@ impl<St: Stream> Stream for Skip<St> {
 
         self.as_mut().stream().poll_next(cx)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = cmp::min(self.remaining, usize::max_value() as u64) as usize;
+        let (lower, upper) = self.stream.size_hint();
+
+        let lower = lower.saturating_sub(remaining);
+        let upper = upper.map(|x| x.saturating_sub(remaining));
+
+        (lower, upper)
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
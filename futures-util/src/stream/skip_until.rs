@@ -0,0 +1,135 @@
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`skip_until`](super::StreamExt::skip_until) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct SkipUntil<St, Fut> {
+    stream: St,
+    // `None` once the future has resolved.
+    fut: Option<Fut>,
+    done: bool,
+}
+
+impl<St: Unpin, Fut: Unpin> Unpin for SkipUntil<St, Fut> {}
+
+impl<St, Fut> SkipUntil<St, Fut>
+    where St: Stream,
+          Fut: Future,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_pinned!(fut: Option<Fut>);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, fut: Fut) -> SkipUntil<St, Fut> {
+        SkipUntil {
+            stream,
+            fut: Some(fut),
+            done: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut St> {
+        self.stream()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, Fut> Stream for SkipUntil<St, Fut>
+    where St: Stream,
+          Fut: Future,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            if let Some(fut) = self.as_mut().fut().as_pin_mut() {
+                if fut.poll(cx).is_ready() {
+                    self.as_mut().fut().set(None);
+                }
+            }
+
+            let item = ready!(self.as_mut().stream().poll_next(cx));
+            match item {
+                // Items which arrive before the future has resolved are
+                // skipped.
+                Some(_) if self.fut.is_some() => {}
+                Some(item) => return Poll::Ready(Some(item)),
+                None => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        if self.fut.is_none() {
+            return self.stream.size_hint();
+        }
+
+        let (_, upper) = self.stream.size_hint();
+        (0, upper) // can't know a lower bound, due to the future
+    }
+}
+
+impl<St, Fut> FusedStream for SkipUntil<St, Fut>
+    where St: Stream,
+          Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<St, Fut, Item> Sink<Item> for SkipUntil<St, Fut>
+    where St: Stream + Sink<Item>,
+          Fut: Future,
+{
+    type SinkError = St::SinkError;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,115 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`step_by`](super::StreamExt::step_by) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct StepBy<St> {
+    stream: St,
+    step: usize,
+    // The number of items to skip before the next one is yielded.
+    remaining: usize,
+}
+
+impl<St: Unpin> Unpin for StepBy<St> {}
+
+impl<St: Stream> StepBy<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(remaining: usize);
+
+    pub(super) fn new(stream: St, step: usize) -> StepBy<St> {
+        assert!(step != 0);
+
+        StepBy {
+            stream,
+            step,
+            remaining: 0,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut St> {
+        self.stream()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St: FusedStream> FusedStream for StepBy<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St: Stream> Stream for StepBy<St> {
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St::Item>> {
+        loop {
+            let item = match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => item,
+                None => return Poll::Ready(None),
+            };
+            if self.remaining == 0 {
+                *self.as_mut().remaining() = self.step - 1;
+                return Poll::Ready(Some(item));
+            }
+            *self.as_mut().remaining() -= 1;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining;
+        let step = self.step;
+        let yielded = |n: usize| {
+            if n <= remaining {
+                0
+            } else {
+                1 + (n - remaining - 1) / step
+            }
+        };
+
+        let (lower, upper) = self.stream.size_hint();
+        (yielded(lower), upper.map(yielded))
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Item> Sink<Item> for StepBy<S>
+    where S: Stream + Sink<Item>,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
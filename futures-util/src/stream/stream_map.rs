@@ -0,0 +1,221 @@
+//! A set of streams, each identified by a key
+
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::iter::FromIterator;
+use core::mem;
+use core::pin::Pin;
+
+use futures_core::{Poll, Stream, FusedStream};
+use futures_core::task::Context;
+
+use crate::stream::StreamExt;
+
+/// A set of streams, each identified by a key, whose items are yielded
+/// together with the key of the stream they came from.
+///
+/// Like [`SelectAll`](super::SelectAll), this merges the items of a dynamic
+/// set of streams. In addition, streams can be looked up and removed by their
+/// key, and the end of each stream is reported: `StreamMap` yields
+/// `(key, Some(item))` for each item of a stream, and `(key, None)` once that
+/// stream has ended, after which the stream is removed from the map.
+///
+/// The streams are polled in turn, starting from a different stream on each
+/// poll so that a busy stream can't starve the others. Once the map is empty,
+/// it yields `None`, but it can be reused by inserting more streams.
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt, StreamMap};
+///
+/// let mut map = StreamMap::new();
+/// map.insert("a", stream::iter(vec![1, 2]));
+/// map.insert("b", stream::iter(vec![3]));
+/// assert!(map.contains_key(&"a"));
+///
+/// let mut events = map.collect::<Vec<_>>().await;
+/// events.sort();
+/// assert_eq!(events, vec![
+///     ("a", None), ("a", Some(1)), ("a", Some(2)),
+///     ("b", None), ("b", Some(3)),
+/// ]);
+/// # });
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct StreamMap<K, St> {
+    entries: Vec<(K, St)>,
+    // The index of the stream to poll first next time.
+    next: usize,
+    is_terminated: bool,
+}
+
+// Neither the keys nor the streams are ever pinned.
+impl<K, St> Unpin for StreamMap<K, St> {}
+
+impl<K: Debug, St> Debug for StreamMap<K, St> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamMap")
+            .field("keys", &self.entries.iter().map(|(key, _)| key).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<K, St> StreamMap<K, St>
+    where K: Clone + Eq,
+          St: Stream + Unpin,
+{
+    /// Constructs a new, empty `StreamMap`
+    ///
+    /// The returned `StreamMap` does not contain any streams and, in this
+    /// state, `StreamMap::poll_next` will return `Poll::Ready(None)`.
+    pub fn new() -> StreamMap<K, St> {
+        StreamMap {
+            entries: Vec::new(),
+            next: 0,
+            is_terminated: false,
+        }
+    }
+
+    /// Returns the number of streams contained in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map contains no streams.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts a stream into the map under the given key.
+    ///
+    /// If the map already contained a stream under this key, that stream is
+    /// replaced and returned, without its end being reported.
+    ///
+    /// This function will not call `poll` on the submitted stream. The caller
+    /// must ensure that `StreamMap::poll_next` is called in order to receive
+    /// task notifications.
+    pub fn insert(&mut self, key: K, stream: St) -> Option<St> {
+        self.is_terminated = false;
+        match self.entries.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, old)) => Some(mem::replace(old, stream)),
+            None => {
+                self.entries.push((key, stream));
+                None
+            }
+        }
+    }
+
+    /// Removes the stream with the given key from the map, returning it if it
+    /// was present.
+    ///
+    /// The end of a removed stream is not reported.
+    pub fn remove(&mut self, key: &K) -> Option<St> {
+        let idx = self.entries.iter().position(|(k, _)| k == key)?;
+        Some(self.entries.swap_remove(idx).1)
+    }
+
+    /// Returns `true` if the map contains a stream with the given key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.iter().any(|(k, _)| k == key)
+    }
+
+    /// Returns a reference to the stream with the given key.
+    pub fn get(&self, key: &K) -> Option<&St> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, stream)| stream)
+    }
+
+    /// Returns a mutable reference to the stream with the given key.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut St> {
+        self.entries.iter_mut().find(|(k, _)| k == key).map(|(_, stream)| stream)
+    }
+
+    /// Returns an iterator over the keys of the streams in the map, in no
+    /// particular order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+}
+
+impl<K, St> Default for StreamMap<K, St>
+    where K: Clone + Eq,
+          St: Stream + Unpin,
+{
+    fn default() -> StreamMap<K, St> {
+        StreamMap::new()
+    }
+}
+
+impl<K, St> Stream for StreamMap<K, St>
+    where K: Clone + Eq,
+          St: Stream + Unpin,
+{
+    type Item = (K, Option<St::Item>);
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let len = this.entries.len();
+        if len == 0 {
+            this.is_terminated = true;
+            return Poll::Ready(None);
+        }
+
+        let start = this.next % len;
+        this.next = start + 1;
+        for i in 0..len {
+            let idx = (start + i) % len;
+            match this.entries[idx].1.poll_next_unpin(cx) {
+                Poll::Ready(Some(item)) => {
+                    let key = this.entries[idx].0.clone();
+                    return Poll::Ready(Some((key, Some(item))));
+                }
+                Poll::Ready(None) => {
+                    let (key, _) = this.entries.swap_remove(idx);
+                    return Poll::Ready(Some((key, None)));
+                }
+                Poll::Pending => {}
+            }
+        }
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Each stream's end is reported as well.
+        let mut lower = self.entries.len();
+        let mut upper = Some(self.entries.len());
+        for (_, stream) in &self.entries {
+            let (stream_lower, stream_upper) = stream.size_hint();
+            lower = lower.saturating_add(stream_lower);
+            upper = match (upper, stream_upper) {
+                (Some(x), Some(y)) => x.checked_add(y),
+                _ => None,
+            };
+        }
+        (lower, upper)
+    }
+}
+
+impl<K, St> FusedStream for StreamMap<K, St>
+    where K: Clone + Eq,
+          St: Stream + Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.is_terminated
+    }
+}
+
+impl<K, St> FromIterator<(K, St)> for StreamMap<K, St>
+    where K: Clone + Eq,
+          St: Stream + Unpin,
+{
+    fn from_iter<T: IntoIterator<Item = (K, St)>>(iter: T) -> Self {
+        let mut map = StreamMap::new();
+        for (key, stream) in iter {
+            map.insert(key, stream);
+        }
+        map
+    }
+}
@@ -1,3 +1,4 @@
+use core::cmp;
 use core::pin::Pin;
 use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
@@ -78,6 +79,24 @@ impl<St> Stream for Take<St>
             Poll::Ready(next)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.remaining == 0 {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+
+        let lower = cmp::min(lower as u64, self.remaining) as usize;
+
+        let upper = match upper {
+            Some(x) if x as u64 <= self.remaining => Some(x),
+            _ if self.remaining <= usize::max_value() as u64 => Some(self.remaining as usize),
+            _ => None
+        };
+
+        (lower, upper)
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
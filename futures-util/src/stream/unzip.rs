@@ -0,0 +1,129 @@
+use super::fork::poll_half;
+use super::shared::Notifier;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// Stream of the first elements for the [`unzip`](super::StreamExt::unzip)
+/// method.
+#[must_use = "streams do nothing unless polled"]
+pub struct UnzipLeft<St, A, B> {
+    inner: Arc<Inner<St, A, B>>,
+}
+
+/// Stream of the second elements for the [`unzip`](super::StreamExt::unzip)
+/// method.
+#[must_use = "streams do nothing unless polled"]
+pub struct UnzipRight<St, A, B> {
+    inner: Arc<Inner<St, A, B>>,
+}
+
+struct Inner<St, A, B> {
+    state: Mutex<State<St, A, B>>,
+    notifier: Arc<Notifier>,
+}
+
+struct State<St, A, B> {
+    // `None` once the stream has ended.
+    stream: Option<Pin<Box<St>>>,
+    left: VecDeque<A>,
+    right: VecDeque<B>,
+    left_dropped: bool,
+    right_dropped: bool,
+    capacity: usize,
+}
+
+// The stream itself is polled behind the `Arc`, so it won't be moved
+// when the halves are moved.
+impl<St, A, B> Unpin for UnzipLeft<St, A, B> {}
+impl<St, A, B> Unpin for UnzipRight<St, A, B> {}
+
+impl<St, A, B> fmt::Debug for UnzipLeft<St, A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnzipLeft").finish()
+    }
+}
+
+impl<St, A, B> fmt::Debug for UnzipRight<St, A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnzipRight").finish()
+    }
+}
+
+impl<St, A, B> UnzipLeft<St, A, B>
+    where St: Stream<Item = (A, B)>
+{
+    pub(super) fn new(stream: St, capacity: usize) -> (Self, UnzipRight<St, A, B>) {
+        assert!(capacity > 0);
+
+        let inner = Arc::new(Inner {
+            state: Mutex::new(State {
+                stream: Some(Box::pin(stream)),
+                left: VecDeque::new(),
+                right: VecDeque::new(),
+                left_dropped: false,
+                right_dropped: false,
+                capacity,
+            }),
+            notifier: Arc::new(Notifier::new()),
+        });
+        (UnzipLeft { inner: inner.clone() }, UnzipRight { inner })
+    }
+}
+
+impl<St, A, B> Stream for UnzipLeft<St, A, B>
+    where St: Stream<Item = (A, B)>
+{
+    type Item = A;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<A>> {
+        let inner = &*self.inner;
+        let mut state = inner.state.lock().unwrap();
+        let state = &mut *state;
+        let other = if state.right_dropped { None } else { Some(&mut state.right) };
+        poll_half(&mut state.stream, &mut state.left, other, state.capacity, &inner.notifier, cx,
+                  |(a, b)| (Some(a), Some(b)))
+    }
+}
+
+impl<St, A, B> Stream for UnzipRight<St, A, B>
+    where St: Stream<Item = (A, B)>
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<B>> {
+        let inner = &*self.inner;
+        let mut state = inner.state.lock().unwrap();
+        let state = &mut *state;
+        let other = if state.left_dropped { None } else { Some(&mut state.left) };
+        poll_half(&mut state.stream, &mut state.right, other, state.capacity, &inner.notifier, cx,
+                  |(a, b)| (Some(b), Some(a)))
+    }
+}
+
+impl<St, A, B> Drop for UnzipLeft<St, A, B> {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.inner.state.lock() {
+            // The first elements are discarded from now on.
+            state.left_dropped = true;
+            state.left.clear();
+            // The other half may have been waiting for this one.
+            self.inner.notifier.wake_all();
+        }
+    }
+}
+
+impl<St, A, B> Drop for UnzipRight<St, A, B> {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.inner.state.lock() {
+            // The second elements are discarded from now on.
+            state.right_dropped = true;
+            state.right.clear();
+            // The other half may have been waiting for this one.
+            self.inner.notifier.wake_all();
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::stream::Fuse;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use core::pin::Pin;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// Stream for the [`windows`](super::StreamExt::windows) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Windows<St: Stream> {
+    stream: Fuse<St>,
+    // The items of the last window yielded, or of the first one so far.
+    window: VecDeque<St::Item>,
+    size: usize,
+}
+
+impl<St: Unpin + Stream> Unpin for Windows<St> {}
+
+impl<St: Stream> Windows<St> where St::Item: Clone {
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(window: VecDeque<St::Item>);
+
+    pub(super) fn new(stream: St, size: usize) -> Windows<St> {
+        assert!(size > 0);
+
+        Windows {
+            stream: super::Fuse::new(stream),
+            window: VecDeque::with_capacity(size),
+            size,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut St> {
+        self.stream().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St: Stream> Stream for Windows<St> where St::Item: Clone {
+    type Item = Vec<St::Item>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            let item = match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => item,
+                None => return Poll::Ready(None),
+            };
+
+            let size = self.size;
+            let window = self.as_mut().window();
+            if window.len() == size {
+                window.pop_front();
+            }
+            window.push_back(item);
+            if window.len() == size {
+                return Poll::Ready(Some(window.iter().cloned().collect()));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every item completes a window once `size - 1` items are buffered.
+        let buffered = self.window.len().min(self.size - 1);
+        let windows = |n: usize| n.saturating_add(buffered).saturating_sub(self.size - 1);
+
+        let (lower, upper) = self.stream.size_hint();
+        (windows(lower), upper.map(windows))
+    }
+}
+
+impl<St: Stream> FusedStream for Windows<St> where St::Item: Clone {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Item> Sink<Item> for Windows<S>
+where
+    S: Stream + Sink<Item>,
+    S::Item: Clone,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
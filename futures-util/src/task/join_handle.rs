@@ -0,0 +1,203 @@
+use crate::future::{AbortHandle, Abortable, CatchUnwind, FutureExt};
+use futures_channel::oneshot::{self, Receiver, Sender};
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A handle to a task spawned with
+/// [`spawn_with_join_handle`](crate::task::SpawnExt::spawn_with_join_handle)
+/// or
+/// [`spawn_local_with_join_handle`](crate::task::LocalSpawnExt::spawn_local_with_join_handle).
+///
+/// The handle is a future which resolves to the task's output, or to a
+/// [`JoinError`] if the task panicked or was cancelled. Unlike
+/// [`RemoteHandle`](crate::future::RemoteHandle), dropping a `JoinHandle`
+/// detaches the task and lets it keep running, unless
+/// [`abort_on_drop`](JoinHandle::abort_on_drop) was called.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct JoinHandle<T> {
+    rx: Receiver<Result<T, JoinError>>,
+    abort_handle: AbortHandle,
+    finished: Arc<AtomicBool>,
+    abort_on_drop: bool,
+}
+
+impl<T> JoinHandle<T> {
+    /// Aborts the task associated with this handle.
+    ///
+    /// The task is dropped the next time the executor polls it, and this
+    /// handle then resolves to a cancelled [`JoinError`]. Aborting a task
+    /// which has already finished has no effect.
+    pub fn abort(&self) {
+        self.abort_handle.abort();
+    }
+
+    /// Returns `true` if the task has finished running, either because it
+    /// completed, panicked, or was cancelled.
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::SeqCst)
+    }
+
+    /// Makes dropping this handle abort the task instead of detaching it.
+    pub fn abort_on_drop(mut self) -> Self {
+        self.abort_on_drop = true;
+        self
+    }
+}
+
+impl<T> Drop for JoinHandle<T> {
+    fn drop(&mut self) {
+        if self.abort_on_drop {
+            self.abort_handle.abort();
+        }
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match ready!(self.rx.poll_unpin(cx)) {
+            Ok(output) => Poll::Ready(output),
+            // The executor dropped the task before it completed.
+            Err(_) => Poll::Ready(Err(JoinError::cancelled())),
+        }
+    }
+}
+
+/// The error returned by a [`JoinHandle`] when its task did not complete.
+pub struct JoinError {
+    repr: Repr,
+}
+
+enum Repr {
+    Cancelled,
+    Panic(Box<dyn Any + Send + 'static>),
+}
+
+impl JoinError {
+    fn cancelled() -> JoinError {
+        JoinError { repr: Repr::Cancelled }
+    }
+
+    /// Returns `true` if the task was cancelled, either through
+    /// [`JoinHandle::abort`] or because the executor dropped it.
+    pub fn is_cancelled(&self) -> bool {
+        match self.repr {
+            Repr::Cancelled => true,
+            Repr::Panic(_) => false,
+        }
+    }
+
+    /// Returns `true` if the task panicked.
+    pub fn is_panic(&self) -> bool {
+        match self.repr {
+            Repr::Cancelled => false,
+            Repr::Panic(_) => true,
+        }
+    }
+
+    /// Consumes this error, returning the payload the task panicked with.
+    ///
+    /// If the task was cancelled, the error is returned unchanged.
+    pub fn try_into_panic(self) -> Result<Box<dyn Any + Send + 'static>, JoinError> {
+        match self.repr {
+            Repr::Panic(payload) => Ok(payload),
+            Repr::Cancelled => Err(self),
+        }
+    }
+}
+
+impl fmt::Debug for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.repr {
+            Repr::Cancelled => f.debug_tuple("JoinError").field(&"cancelled").finish(),
+            Repr::Panic(_) => f.debug_tuple("JoinError").field(&"panic").finish(),
+        }
+    }
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.repr {
+            Repr::Cancelled => write!(f, "task was cancelled"),
+            Repr::Panic(_) => write!(f, "task panicked"),
+        }
+    }
+}
+
+impl Error for JoinError {}
+
+type TaskOutput<Fut> = Result<<Fut as Future>::Output, JoinError>;
+
+/// A future which runs a task and sends its outcome to the corresponding
+/// `JoinHandle`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub(super) struct JoinTask<Fut: Future> {
+    tx: Option<Sender<TaskOutput<Fut>>>,
+    finished: Arc<AtomicBool>,
+    future: Abortable<CatchUnwind<AssertUnwindSafe<Fut>>>,
+}
+
+impl<Fut: Future + Unpin> Unpin for JoinTask<Fut> {}
+
+impl<Fut: Future> JoinTask<Fut> {
+    unsafe_pinned!(future: Abortable<CatchUnwind<AssertUnwindSafe<Fut>>>);
+    unsafe_unpinned!(tx: Option<Sender<TaskOutput<Fut>>>);
+}
+
+impl<Fut: Future> Future for JoinTask<Fut> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let output = match ready!(self.as_mut().future().poll(cx)) {
+            Ok(Ok(output)) => Ok(output),
+            Ok(Err(payload)) => Err(JoinError { repr: Repr::Panic(payload) }),
+            Err(_aborted) => Err(JoinError::cancelled()),
+        };
+        self.finished.store(true, Ordering::SeqCst);
+
+        // if the receiving end has gone away then that's ok, we just ignore the
+        // send error here.
+        drop(self.as_mut().tx().take().unwrap().send(output));
+        Poll::Ready(())
+    }
+}
+
+impl<Fut: Future> Drop for JoinTask<Fut> {
+    fn drop(&mut self) {
+        self.finished.store(true, Ordering::SeqCst);
+    }
+}
+
+pub(super) fn join_handle<Fut: Future>(future: Fut) -> (JoinTask<Fut>, JoinHandle<Fut::Output>) {
+    let (tx, rx) = oneshot::channel();
+    let (abort_handle, abort_registration) = AbortHandle::new_pair();
+    let finished = Arc::new(AtomicBool::new(false));
+
+    // AssertUnwindSafe is used here because `Send + 'static` is basically
+    // an alias for an implementation of the `UnwindSafe` trait but we can't
+    // express that in the standard library right now.
+    let task = JoinTask {
+        tx: Some(tx),
+        finished: finished.clone(),
+        future: Abortable::new(AssertUnwindSafe(future).catch_unwind(), abort_registration),
+    };
+
+    let handle = JoinHandle {
+        rx,
+        abort_handle,
+        finished,
+        abort_on_drop: false,
+    };
+
+    (task, handle)
+}
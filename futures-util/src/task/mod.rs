@@ -26,6 +26,11 @@ cfg_target_has_atomic! {
     pub use self::waker_ref::{waker_ref, WakerRef};
 
     pub use futures_core::task::__internal::AtomicWaker;
+
+    #[cfg(feature = "std")]
+    mod join_handle;
+    #[cfg(feature = "std")]
+    pub use self::join_handle::{JoinHandle, JoinError};
 }
 
 mod noop_waker;
@@ -36,6 +41,11 @@ pub use self::noop_waker::noop_waker_ref;
 mod spawn;
 pub use self::spawn::{SpawnExt, LocalSpawnExt};
 
+#[cfg(feature = "alloc")]
+mod task_builder;
+#[cfg(feature = "alloc")]
+pub use self::task_builder::TaskBuilder;
+
 // re-export for `select!`
 #[doc(hidden)]
 pub use futures_core::task::{Context, Poll, Waker};
@@ -4,12 +4,20 @@ use futures_core::task::{LocalSpawn, Spawn};
 
 #[cfg(feature = "std")]
 use crate::future::{FutureExt, RemoteHandle};
+#[cfg_attr(
+    feature = "cfg-target-has-atomic",
+    cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+)]
+#[cfg(feature = "std")]
+use super::join_handle::{join_handle, JoinHandle};
 #[cfg(feature = "alloc")]
 use futures_core::future::{Future, FutureObj, LocalFutureObj};
 #[cfg(feature = "alloc")]
 use futures_core::task::SpawnError;
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use super::TaskBuilder;
 
 impl<Sp: ?Sized> SpawnExt for Sp where Sp: Spawn {}
 impl<Sp: ?Sized> LocalSpawnExt for Sp where Sp: LocalSpawn {}
@@ -82,6 +90,65 @@ pub trait SpawnExt: Spawn {
         Ok(handle)
     }
 
+    /// Spawns a task that polls the given future to completion and returns a
+    /// [`JoinHandle`](crate::task::JoinHandle) which can be used to await
+    /// its output or to abort it.
+    ///
+    /// The handle resolves to a [`JoinError`](crate::task::JoinError) if the
+    /// task panics or is cancelled. Dropping the handle detaches the task.
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::executor::ThreadPool;
+    /// use futures::task::SpawnExt;
+    ///
+    /// let mut executor = ThreadPool::new().unwrap();
+    ///
+    /// let handle = executor.spawn_with_join_handle(async { 1 }).unwrap();
+    /// assert_eq!(executor.run(handle).unwrap(), 1);
+    ///
+    /// let handle = executor.spawn_with_join_handle(async { panic!() }).unwrap();
+    /// assert!(executor.run(handle).unwrap_err().is_panic());
+    /// ```
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "std")]
+    fn spawn_with_join_handle<Fut>(
+        &mut self,
+        future: Fut
+    ) -> Result<JoinHandle<Fut::Output>, SpawnError>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send,
+    {
+        let (future, handle) = join_handle(future);
+        self.spawn(future)?;
+        Ok(handle)
+    }
+
+    /// Creates a [`TaskBuilder`](crate::task::TaskBuilder) for spawning a
+    /// task with a name and other metadata.
+    ///
+    /// The metadata is passed to the executor through
+    /// [`Spawn::spawn_obj_with_metadata`].
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::executor::ThreadPool;
+    /// use futures::task::SpawnExt;
+    ///
+    /// let mut executor = ThreadPool::new().unwrap();
+    ///
+    /// let future = async { /* ... */ };
+    /// executor.build_task().name("conn-handler").spawn(future).unwrap();
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn build_task(&mut self) -> TaskBuilder<'_, Self> {
+        TaskBuilder::new(self)
+    }
+
     /// Wraps a [`Spawn`] and makes it usable as a futures 0.1 `Executor`.
     /// Requires the `compat` feature to enable.
     #[cfg(feature = "compat")]
@@ -160,4 +227,63 @@ pub trait LocalSpawnExt: LocalSpawn {
         self.spawn_local(future)?;
         Ok(handle)
     }
+
+    /// Spawns a task that polls the given future to completion and returns a
+    /// [`JoinHandle`](crate::task::JoinHandle) which can be used to await
+    /// its output or to abort it.
+    ///
+    /// The handle resolves to a [`JoinError`](crate::task::JoinError) if the
+    /// task panics or is cancelled. Dropping the handle detaches the task.
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::executor::LocalPool;
+    /// use futures::future;
+    /// use futures::task::LocalSpawnExt;
+    ///
+    /// let mut executor = LocalPool::new();
+    /// let mut spawner = executor.spawner();
+    ///
+    /// let handle = spawner.spawn_local_with_join_handle(future::pending::<()>()).unwrap();
+    /// handle.abort();
+    /// assert!(executor.run_until(handle).unwrap_err().is_cancelled());
+    /// ```
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "std")]
+    fn spawn_local_with_join_handle<Fut>(
+        &mut self,
+        future: Fut
+    ) -> Result<JoinHandle<Fut::Output>, SpawnError>
+    where
+        Fut: Future + 'static,
+    {
+        let (future, handle) = join_handle(future);
+        self.spawn_local(future)?;
+        Ok(handle)
+    }
+
+    /// Creates a [`TaskBuilder`](crate::task::TaskBuilder) for spawning a
+    /// task with a name and other metadata.
+    ///
+    /// The metadata is passed to the executor through
+    /// [`LocalSpawn::spawn_local_obj_with_metadata`].
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::executor::LocalPool;
+    /// use futures::task::LocalSpawnExt;
+    ///
+    /// let executor = LocalPool::new();
+    /// let mut spawner = executor.spawner();
+    ///
+    /// let future = async { /* ... */ };
+    /// spawner.build_local_task().name("ui-event").spawn_local(future).unwrap();
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn build_local_task(&mut self) -> TaskBuilder<'_, Self> {
+        TaskBuilder::new(self)
+    }
 }
@@ -0,0 +1,199 @@
+use futures_core::future::{Future, FutureObj, LocalFutureObj};
+use futures_core::task::{LocalSpawn, Spawn, SpawnError, SpawnLocation, TaskMetadata};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use core::any::Any;
+
+#[cfg(feature = "std")]
+use crate::future::{FutureExt, RemoteHandle};
+
+/// Builder for spawning a task along with [`TaskMetadata`] describing it.
+///
+/// Values of this type are created by the
+/// [`build_task`](crate::task::SpawnExt::build_task) and
+/// [`build_local_task`](crate::task::LocalSpawnExt::build_local_task)
+/// methods. Executors which don't make use of the metadata spawn the task
+/// as if it was spawned without it.
+#[derive(Debug)]
+#[must_use = "tasks are not spawned until one of the `spawn` methods is called"]
+pub struct TaskBuilder<'a, Sp: ?Sized> {
+    spawner: &'a mut Sp,
+    metadata: TaskMetadata,
+}
+
+impl<'a, Sp: ?Sized> TaskBuilder<'a, Sp> {
+    pub(super) fn new(spawner: &'a mut Sp) -> TaskBuilder<'a, Sp> {
+        TaskBuilder {
+            spawner,
+            metadata: TaskMetadata::new(),
+        }
+    }
+
+    /// Sets the name of the task.
+    pub fn name<N: Into<Cow<'static, str>>>(mut self, name: N) -> Self {
+        self.metadata.set_name(name);
+        self
+    }
+
+    /// Records the location the task is spawned from, so that executors can
+    /// point at it in debug output.
+    ///
+    /// The [`build_task!`](crate::build_task) and
+    /// [`build_local_task!`](crate::build_local_task) macros set it to the
+    /// location they are invoked from.
+    ///
+    /// ```
+    /// use futures::executor::LocalPool;
+    /// use futures::future::ready;
+    /// use futures::task::LocalSpawnExt;
+    ///
+    /// let executor = LocalPool::new();
+    /// let mut spawner = executor.spawner();
+    ///
+    /// spawner.build_local_task()
+    ///     .location(file!(), line!())
+    ///     .spawn_local(ready(()))
+    ///     .unwrap();
+    /// ```
+    pub fn location(mut self, file: &'static str, line: u32) -> Self {
+        self.metadata.set_location(SpawnLocation::new(file, line));
+        self
+    }
+
+    /// Attaches an additional value to the task's metadata, replacing any
+    /// value of the same type.
+    pub fn metadata<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.metadata.insert(value);
+        self
+    }
+
+    /// Spawns a task that polls the given future with output `()` to
+    /// completion.
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::executor::ThreadPool;
+    /// use futures::task::SpawnExt;
+    ///
+    /// let mut executor = ThreadPool::new().unwrap();
+    ///
+    /// let future = async { /* ... */ };
+    /// executor.build_task().name("conn-handler").spawn(future).unwrap();
+    /// ```
+    pub fn spawn<Fut>(self, future: Fut) -> Result<(), SpawnError>
+    where
+        Sp: Spawn,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.spawner.spawn_obj_with_metadata(FutureObj::new(Box::new(future)), self.metadata)
+    }
+
+    /// Spawns a task that polls the given future to completion and returns a
+    /// future that resolves to the spawned future's output.
+    ///
+    /// See [`SpawnExt::spawn_with_handle`](crate::task::SpawnExt::spawn_with_handle).
+    #[cfg(feature = "std")]
+    pub fn spawn_with_handle<Fut>(
+        self,
+        future: Fut
+    ) -> Result<RemoteHandle<Fut::Output>, SpawnError>
+    where
+        Sp: Spawn,
+        Fut: Future + Send + 'static,
+        Fut::Output: Send,
+    {
+        let (future, handle) = future.remote_handle();
+        self.spawn(future)?;
+        Ok(handle)
+    }
+
+    /// Spawns a task that polls the given future with output `()` to
+    /// completion.
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::executor::LocalPool;
+    /// use futures::task::LocalSpawnExt;
+    ///
+    /// let executor = LocalPool::new();
+    /// let mut spawner = executor.spawner();
+    ///
+    /// let future = async { /* ... */ };
+    /// spawner.build_local_task().name("ui-event").spawn_local(future).unwrap();
+    /// ```
+    pub fn spawn_local<Fut>(self, future: Fut) -> Result<(), SpawnError>
+    where
+        Sp: LocalSpawn,
+        Fut: Future<Output = ()> + 'static,
+    {
+        self.spawner.spawn_local_obj_with_metadata(LocalFutureObj::new(Box::new(future)), self.metadata)
+    }
+
+    /// Spawns a task that polls the given future to completion and returns a
+    /// future that resolves to the spawned future's output.
+    ///
+    /// See [`LocalSpawnExt::spawn_local_with_handle`](crate::task::LocalSpawnExt::spawn_local_with_handle).
+    #[cfg(feature = "std")]
+    pub fn spawn_local_with_handle<Fut>(
+        self,
+        future: Fut
+    ) -> Result<RemoteHandle<Fut::Output>, SpawnError>
+    where
+        Sp: LocalSpawn,
+        Fut: Future + 'static,
+    {
+        let (future, handle) = future.remote_handle();
+        self.spawn_local(future)?;
+        Ok(handle)
+    }
+}
+
+/// Creates a [`TaskBuilder`](crate::task::TaskBuilder) for spawning a task
+/// onto the given spawner, recording the location of the macro invocation.
+///
+/// `build_task!(spawner)` is equivalent to
+/// `spawner.build_task().location(file!(), line!())`, see
+/// [`SpawnExt::build_task`](crate::task::SpawnExt::build_task).
+///
+/// ```
+/// #![feature(async_await)]
+/// use futures::build_task;
+/// use futures::executor::ThreadPool;
+///
+/// let mut executor = ThreadPool::new().unwrap();
+///
+/// let future = async { /* ... */ };
+/// build_task!(executor).name("conn-handler").spawn(future).unwrap();
+/// ```
+#[macro_export]
+macro_rules! build_task {
+    ($spawner:expr) => {
+        $crate::task::SpawnExt::build_task(&mut $spawner).location(file!(), line!())
+    }
+}
+
+/// Creates a [`TaskBuilder`](crate::task::TaskBuilder) for spawning a task
+/// onto the given local spawner, recording the location of the macro
+/// invocation.
+///
+/// `build_local_task!(spawner)` is equivalent to
+/// `spawner.build_local_task().location(file!(), line!())`, see
+/// [`LocalSpawnExt::build_local_task`](crate::task::LocalSpawnExt::build_local_task).
+///
+/// ```
+/// #![feature(async_await)]
+/// use futures::build_local_task;
+/// use futures::executor::LocalPool;
+///
+/// let executor = LocalPool::new();
+/// let mut spawner = executor.spawner();
+///
+/// let future = async { /* ... */ };
+/// build_local_task!(spawner).name("ui-event").spawn_local(future).unwrap();
+/// ```
+#[macro_export]
+macro_rules! build_local_task {
+    ($spawner:expr) => {
+        $crate::task::LocalSpawnExt::build_local_task(&mut $spawner).location(file!(), line!())
+    }
+}
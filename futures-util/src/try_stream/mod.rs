@@ -56,6 +56,33 @@ pub use self::try_concat::TryConcat;
 mod try_fold;
 pub use self::try_fold::TryFold;
 
+mod try_reduce;
+pub use self::try_reduce::TryReduce;
+
+mod try_count;
+pub use self::try_count::TryCount;
+
+mod try_any;
+pub use self::try_any::TryAny;
+
+mod try_all;
+pub use self::try_all::TryAll;
+
+mod try_find;
+pub use self::try_find::TryFind;
+
+mod try_position;
+pub use self::try_position::TryPosition;
+
+mod try_min_by_key;
+pub use self::try_min_by_key::TryMinByKey;
+
+mod try_max_by_key;
+pub use self::try_max_by_key::TryMaxByKey;
+
+mod try_last;
+pub use self::try_last::TryLast;
+
 mod try_skip_while;
 pub use self::try_skip_while::TrySkipWhile;
 
@@ -594,6 +621,264 @@ pub trait TryStreamExt: TryStream {
         TryFold::new(self, f, init)
     }
 
+    /// Attempt to execute an accumulating asynchronous computation over a
+    /// stream, starting with its first value.
+    ///
+    /// This method is similar to [`reduce`](super::StreamExt::reduce), but
+    /// will exit early if an error is encountered in either the stream or the
+    /// provided closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let number_stream = stream::iter(vec![Ok::<i32, i32>(2), Ok(3)]);
+    /// let product = number_stream.try_reduce(|acc, x| future::ready(Ok(acc * x)));
+    /// assert_eq!(product.await, Ok(Some(6)));
+    ///
+    /// let number_stream_with_err = stream::iter(vec![Ok::<i32, i32>(1), Err(2), Ok(1)]);
+    /// let product = number_stream_with_err.try_reduce(|acc, x| future::ready(Ok(acc * x)));
+    /// assert_eq!(product.await, Err(2));
+    /// # })
+    /// ```
+    fn try_reduce<Fut, F>(self, f: F) -> TryReduce<Self, Fut, F>
+        where F: FnMut(Self::Ok, Self::Ok) -> Fut,
+              Fut: TryFuture<Ok = Self::Ok, Error = Self::Error>,
+              Self: Sized,
+    {
+        TryReduce::new(self, f)
+    }
+
+    /// Attempt to count the values of the stream.
+    ///
+    /// This method is similar to [`count`](super::StreamExt::count), but will
+    /// exit early if an error is encountered in the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2)]);
+    /// assert_eq!(stream.try_count().await, Ok(2));
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Err(2), Ok(3)]);
+    /// assert_eq!(stream.try_count().await, Err(2));
+    /// # })
+    /// ```
+    fn try_count(self) -> TryCount<Self>
+        where Self: Sized,
+    {
+        TryCount::new(self)
+    }
+
+    /// Attempt to check whether any value of the stream matches an
+    /// asynchronous predicate.
+    ///
+    /// This method is similar to [`any`](super::StreamExt::any), but will
+    /// exit early if an error is encountered in the stream before a value
+    /// matched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2), Err(3)]);
+    /// assert_eq!(stream.try_any(|x| future::ready(x == 2)).await, Ok(true));
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Err(3), Ok(2)]);
+    /// assert_eq!(stream.try_any(|x| future::ready(x == 2)).await, Err(3));
+    /// # })
+    /// ```
+    fn try_any<Fut, F>(self, f: F) -> TryAny<Self, Fut, F>
+        where F: FnMut(Self::Ok) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized,
+    {
+        TryAny::new(self, f)
+    }
+
+    /// Attempt to check whether all values of the stream match an
+    /// asynchronous predicate.
+    ///
+    /// This method is similar to [`all`](super::StreamExt::all), but will
+    /// exit early if an error is encountered in the stream before a value
+    /// failed to match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(2), Ok(4)]);
+    /// assert_eq!(stream.try_all(|x| future::ready(x % 2 == 0)).await, Ok(true));
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(2), Err(3), Ok(5)]);
+    /// assert_eq!(stream.try_all(|x| future::ready(x % 2 == 0)).await, Err(3));
+    /// # })
+    /// ```
+    fn try_all<Fut, F>(self, f: F) -> TryAll<Self, Fut, F>
+        where F: FnMut(Self::Ok) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized,
+    {
+        TryAll::new(self, f)
+    }
+
+    /// Attempt to search the stream for the first value which matches an
+    /// asynchronous predicate.
+    ///
+    /// This method is similar to [`find`](super::StreamExt::find), but will
+    /// exit early if an error is encountered in the stream before a value
+    /// matched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(4), Err(3)]);
+    /// assert_eq!(stream.try_find(|x| future::ready(x % 2 == 0)).await, Ok(Some(4)));
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Err(3), Ok(4)]);
+    /// assert_eq!(stream.try_find(|x| future::ready(x % 2 == 0)).await, Err(3));
+    /// # })
+    /// ```
+    fn try_find<Fut, F>(self, f: F) -> TryFind<Self, Fut, F>
+        where F: FnMut(&Self::Ok) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized,
+    {
+        TryFind::new(self, f)
+    }
+
+    /// Attempt to search the stream for the first value which matches an
+    /// asynchronous predicate, returning its index.
+    ///
+    /// This method is similar to [`position`](super::StreamExt::position),
+    /// but will exit early if an error is encountered in the stream before a
+    /// value matched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(4), Err(3)]);
+    /// assert_eq!(stream.try_position(|x| future::ready(x % 2 == 0)).await, Ok(Some(1)));
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Err(3), Ok(4)]);
+    /// assert_eq!(stream.try_position(|x| future::ready(x % 2 == 0)).await, Err(3));
+    /// # })
+    /// ```
+    fn try_position<Fut, F>(self, f: F) -> TryPosition<Self, Fut, F>
+        where F: FnMut(Self::Ok) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized,
+    {
+        TryPosition::new(self, f)
+    }
+
+    /// Attempt to find the value of the stream for which the given function
+    /// returns the minimum key.
+    ///
+    /// This method is similar to [`min_by_key`](super::StreamExt::min_by_key),
+    /// but will exit early if an error is encountered in the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(-3), Ok(1), Ok(2)]);
+    /// assert_eq!(stream.try_min_by_key(|x: &i32| x.abs()).await, Ok(Some(1)));
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(-3), Err(1), Ok(2)]);
+    /// assert_eq!(stream.try_min_by_key(|x: &i32| x.abs()).await, Err(1));
+    /// # })
+    /// ```
+    fn try_min_by_key<K, F>(self, f: F) -> TryMinByKey<Self, K, F>
+        where F: FnMut(&Self::Ok) -> K,
+              K: Ord,
+              Self: Sized,
+    {
+        TryMinByKey::new(self, f)
+    }
+
+    /// Attempt to find the value of the stream for which the given function
+    /// returns the maximum key.
+    ///
+    /// This method is similar to [`max_by_key`](super::StreamExt::max_by_key),
+    /// but will exit early if an error is encountered in the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(-3), Ok(1), Ok(2)]);
+    /// assert_eq!(stream.try_max_by_key(|x: &i32| x.abs()).await, Ok(Some(-3)));
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(-3), Err(1), Ok(2)]);
+    /// assert_eq!(stream.try_max_by_key(|x: &i32| x.abs()).await, Err(1));
+    /// # })
+    /// ```
+    fn try_max_by_key<K, F>(self, f: F) -> TryMaxByKey<Self, K, F>
+        where F: FnMut(&Self::Ok) -> K,
+              K: Ord,
+              Self: Sized,
+    {
+        TryMaxByKey::new(self, f)
+    }
+
+    /// Attempt to find the last value of the stream.
+    ///
+    /// This method is similar to [`last`](super::StreamExt::last), but will
+    /// exit early if an error is encountered in the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2)]);
+    /// assert_eq!(stream.try_last().await, Ok(Some(2)));
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Err(2), Ok(3)]);
+    /// assert_eq!(stream.try_last().await, Err(2));
+    /// # })
+    /// ```
+    fn try_last(self) -> TryLast<Self>
+        where Self: Sized,
+    {
+        TryLast::new(self)
+    }
+
     /// Attempt to concatenate all items of a stream into a single
     /// extendable destination, returning a future representing the end result.
     ///
@@ -0,0 +1,41 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, TryStream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`try_count`](super::TryStreamExt::try_count) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryCount<St> {
+    stream: St,
+    count: usize,
+}
+
+impl<St: Unpin> Unpin for TryCount<St> {}
+
+impl<St: TryStream> TryCount<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(count: usize);
+
+    pub(super) fn new(stream: St) -> TryCount<St> {
+        TryCount { stream, count: 0 }
+    }
+}
+
+impl<St: FusedStream> FusedFuture for TryCount<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St: TryStream> Future for TryCount<St> {
+    type Output = Result<usize, St::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        while ready!(self.as_mut().stream().try_poll_next(cx)?).is_some() {
+            *self.as_mut().count() += 1;
+        }
+        Poll::Ready(Ok(self.count))
+    }
+}
@@ -0,0 +1,108 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::TryStream;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`try_find`](super::TryStreamExt::try_find) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryFind<St, Fut, F>
+    where St: TryStream,
+{
+    stream: St,
+    f: F,
+    pending_fut: Option<Fut>,
+    pending_item: Option<St::Ok>,
+    done: bool,
+}
+
+impl<St, Fut, F> Unpin for TryFind<St, Fut, F>
+where
+    St: TryStream + Unpin,
+    Fut: Unpin,
+{}
+
+impl<St, Fut, F> fmt::Debug for TryFind<St, Fut, F>
+where
+    St: TryStream + fmt::Debug,
+    St::Ok: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryFind")
+            .field("stream", &self.stream)
+            .field("pending_fut", &self.pending_fut)
+            .field("pending_item", &self.pending_item)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> TryFind<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(&St::Ok) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(pending_fut: Option<Fut>);
+    unsafe_unpinned!(pending_item: Option<St::Ok>);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, f: F) -> TryFind<St, Fut, F> {
+        TryFind {
+            stream,
+            f,
+            pending_fut: None,
+            pending_item: None,
+            done: false,
+        }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for TryFind<St, Fut, F>
+    where St: TryStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St, Fut, F> Future for TryFind<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(&St::Ok) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    type Output = Result<Option<St::Ok>, St::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if self.pending_fut.is_none() {
+                let item = match ready!(self.as_mut().stream().try_poll_next(cx)) {
+                    Some(Ok(x)) => x,
+                    Some(Err(e)) => {
+                        *self.as_mut().done() = true;
+                        return Poll::Ready(Err(e));
+                    }
+                    None => {
+                        *self.as_mut().done() = true;
+                        return Poll::Ready(Ok(None));
+                    }
+                };
+                let fut = (self.as_mut().f())(&item);
+                self.as_mut().pending_fut().set(Some(fut));
+                *self.as_mut().pending_item() = Some(item);
+            }
+
+            let found = ready!(self.as_mut().pending_fut().as_pin_mut().unwrap().poll(cx));
+            self.as_mut().pending_fut().set(None);
+            let item = self.as_mut().pending_item().take().unwrap();
+
+            if found {
+                *self.as_mut().done() = true;
+                return Poll::Ready(Ok(Some(item)));
+            }
+        }
+    }
+}
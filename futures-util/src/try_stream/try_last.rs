@@ -0,0 +1,41 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, TryStream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`try_last`](super::TryStreamExt::try_last) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryLast<St: TryStream> {
+    stream: St,
+    last: Option<St::Ok>,
+}
+
+impl<St: TryStream + Unpin> Unpin for TryLast<St> {}
+
+impl<St: TryStream> TryLast<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(last: Option<St::Ok>);
+
+    pub(super) fn new(stream: St) -> TryLast<St> {
+        TryLast { stream, last: None }
+    }
+}
+
+impl<St: TryStream + FusedStream> FusedFuture for TryLast<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St: TryStream> Future for TryLast<St> {
+    type Output = Result<Option<St::Ok>, St::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        while let Some(item) = ready!(self.as_mut().stream().try_poll_next(cx)?) {
+            *self.as_mut().last() = Some(item);
+        }
+        Poll::Ready(Ok(self.as_mut().last().take()))
+    }
+}
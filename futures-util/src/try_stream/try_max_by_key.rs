@@ -0,0 +1,75 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, TryStream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`try_max_by_key`](super::TryStreamExt::try_max_by_key)
+/// method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryMaxByKey<St: TryStream, K, F> {
+    stream: St,
+    f: F,
+    max: Option<(K, St::Ok)>,
+}
+
+impl<St: TryStream + Unpin, K, F> Unpin for TryMaxByKey<St, K, F> {}
+
+impl<St, K, F> fmt::Debug for TryMaxByKey<St, K, F>
+where
+    St: TryStream + fmt::Debug,
+    St::Ok: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryMaxByKey")
+            .field("stream", &self.stream)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<St, K, F> TryMaxByKey<St, K, F>
+    where St: TryStream,
+          K: Ord,
+          F: FnMut(&St::Ok) -> K,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(max: Option<(K, St::Ok)>);
+
+    pub(super) fn new(stream: St, f: F) -> TryMaxByKey<St, K, F> {
+        TryMaxByKey { stream, f, max: None }
+    }
+}
+
+impl<St, K, F> FusedFuture for TryMaxByKey<St, K, F>
+    where St: TryStream + FusedStream,
+          K: Ord,
+          F: FnMut(&St::Ok) -> K,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, K, F> Future for TryMaxByKey<St, K, F>
+    where St: TryStream,
+          K: Ord,
+          F: FnMut(&St::Ok) -> K,
+{
+    type Output = Result<Option<St::Ok>, St::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        while let Some(item) = ready!(self.as_mut().stream().try_poll_next(cx)?) {
+            let key = (self.as_mut().f())(&item);
+            // The last of several maximal items is kept.
+            match &self.max {
+                Some((max, _)) if key < *max => {}
+                _ => *self.as_mut().max() = Some((key, item)),
+            }
+        }
+        Poll::Ready(Ok(self.as_mut().max().take().map(|(_, item)| item)))
+    }
+}
@@ -0,0 +1,75 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, TryStream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`try_min_by_key`](super::TryStreamExt::try_min_by_key)
+/// method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryMinByKey<St: TryStream, K, F> {
+    stream: St,
+    f: F,
+    min: Option<(K, St::Ok)>,
+}
+
+impl<St: TryStream + Unpin, K, F> Unpin for TryMinByKey<St, K, F> {}
+
+impl<St, K, F> fmt::Debug for TryMinByKey<St, K, F>
+where
+    St: TryStream + fmt::Debug,
+    St::Ok: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryMinByKey")
+            .field("stream", &self.stream)
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<St, K, F> TryMinByKey<St, K, F>
+    where St: TryStream,
+          K: Ord,
+          F: FnMut(&St::Ok) -> K,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(min: Option<(K, St::Ok)>);
+
+    pub(super) fn new(stream: St, f: F) -> TryMinByKey<St, K, F> {
+        TryMinByKey { stream, f, min: None }
+    }
+}
+
+impl<St, K, F> FusedFuture for TryMinByKey<St, K, F>
+    where St: TryStream + FusedStream,
+          K: Ord,
+          F: FnMut(&St::Ok) -> K,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, K, F> Future for TryMinByKey<St, K, F>
+    where St: TryStream,
+          K: Ord,
+          F: FnMut(&St::Ok) -> K,
+{
+    type Output = Result<Option<St::Ok>, St::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        while let Some(item) = ready!(self.as_mut().stream().try_poll_next(cx)?) {
+            let key = (self.as_mut().f())(&item);
+            // The first of several minimal items is kept.
+            match &self.min {
+                Some((min, _)) if key >= *min => {}
+                _ => *self.as_mut().min() = Some((key, item)),
+            }
+        }
+        Poll::Ready(Ok(self.as_mut().min().take().map(|(_, item)| item)))
+    }
+}
@@ -0,0 +1,93 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::TryStream;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`try_position`](super::TryStreamExt::try_position) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryPosition<St, Fut, F> {
+    stream: St,
+    f: F,
+    // The index of the item which is currently being checked, if any.
+    index: usize,
+    future: Option<Fut>,
+    done: bool,
+}
+
+impl<St: Unpin, Fut: Unpin, F> Unpin for TryPosition<St, Fut, F> {}
+
+impl<St, Fut, F> fmt::Debug for TryPosition<St, Fut, F>
+where
+    St: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryPosition")
+            .field("stream", &self.stream)
+            .field("index", &self.index)
+            .field("future", &self.future)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> TryPosition<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(St::Ok) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(index: usize);
+    unsafe_pinned!(future: Option<Fut>);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, f: F) -> TryPosition<St, Fut, F> {
+        TryPosition { stream, f, index: 0, future: None, done: false }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for TryPosition<St, Fut, F> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St, Fut, F> Future for TryPosition<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(St::Ok) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    type Output = Result<Option<usize>, St::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if let Some(future) = self.as_mut().future().as_pin_mut() {
+                let found = ready!(future.poll(cx));
+                self.as_mut().future().set(None);
+                if found {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Ok(Some(self.index)));
+                }
+                *self.as_mut().index() += 1;
+            }
+
+            match ready!(self.as_mut().stream().try_poll_next(cx)) {
+                Some(Ok(item)) => {
+                    let future = (self.as_mut().f())(item);
+                    self.as_mut().future().set(Some(future));
+                }
+                Some(Err(e)) => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Err(e));
+                }
+                None => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Ok(None));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use core::fmt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future, TryFuture};
+use futures_core::stream::{FusedStream, TryStream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`try_reduce`](super::TryStreamExt::try_reduce) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryReduce<St, Fut, F>
+    where St: TryStream,
+{
+    stream: St,
+    f: F,
+    accum: Option<St::Ok>,
+    future: Option<Fut>,
+}
+
+impl<St, Fut, F> Unpin for TryReduce<St, Fut, F>
+where
+    St: TryStream + Unpin,
+    Fut: Unpin,
+{}
+
+impl<St, Fut, F> fmt::Debug for TryReduce<St, Fut, F>
+where
+    St: TryStream + fmt::Debug,
+    St::Ok: fmt::Debug,
+    Fut: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryReduce")
+            .field("stream", &self.stream)
+            .field("accum", &self.accum)
+            .field("future", &self.future)
+            .finish()
+    }
+}
+
+impl<St, Fut, F> TryReduce<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(St::Ok, St::Ok) -> Fut,
+          Fut: TryFuture<Ok = St::Ok, Error = St::Error>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(accum: Option<St::Ok>);
+    unsafe_pinned!(future: Option<Fut>);
+
+    pub(super) fn new(stream: St, f: F) -> TryReduce<St, Fut, F> {
+        TryReduce {
+            stream,
+            f,
+            accum: None,
+            future: None,
+        }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for TryReduce<St, Fut, F>
+    where St: TryStream + FusedStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.future.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St, Fut, F> Future for TryReduce<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(St::Ok, St::Ok) -> Fut,
+          Fut: TryFuture<Ok = St::Ok, Error = St::Error>,
+{
+    type Output = Result<Option<St::Ok>, St::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            // we're currently processing a future to produce a new accum value
+            if let Some(future) = self.as_mut().future().as_pin_mut() {
+                let accum = ready!(future.try_poll(cx));
+                self.as_mut().future().set(None);
+                *self.as_mut().accum() = Some(accum?);
+            }
+
+            let item = match ready!(self.as_mut().stream().try_poll_next(cx)?) {
+                Some(item) => item,
+                None => return Poll::Ready(Ok(self.as_mut().accum().take())),
+            };
+
+            // The first item is the initial accum value.
+            match self.as_mut().accum().take() {
+                Some(accum) => {
+                    let future = (self.as_mut().f())(accum, item);
+                    self.as_mut().future().set(Some(future));
+                }
+                None => *self.as_mut().accum() = Some(item),
+            }
+        }
+    }
+}
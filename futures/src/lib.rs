@@ -69,6 +69,8 @@ compile_error!("The `never-type` feature requires the `nightly` feature as an ex
 // Macro reexports
 pub use futures_util::pin_mut;
 pub use futures_util::ready; // Readiness propagation
+#[cfg(feature = "alloc")]
+pub use futures_util::{build_task, build_local_task}; // Spawning with locations
 #[cfg(feature = "async-await")]
 pub use futures_util::{
     // Async-await
@@ -181,11 +183,14 @@ pub mod executor {
     //! to a global thread pool.
 
     pub use futures_executor::{
-        BlockingStream,
+        BlockingStream, Timeout,
         Enter, EnterError,
-        LocalSpawner, LocalPool,
-        ThreadPool, ThreadPoolBuilder,
-        block_on, block_on_stream, enter,
+        Instrument, TaskId, TaskInfo,
+        LocalSpawner, LocalPool, LocalPoolBuilder, Priority,
+        PanicPolicy, ThreadPool, ThreadPoolBuilder,
+        ThreadPerCore, ThreadPerCoreBuilder,
+        TaskSnapshot, TaskState, Watchdog,
+        block_on, block_on_stream, block_on_timeout, enter,
     };
 }
 
@@ -239,7 +244,16 @@ pub mod future {
     )]
     #[cfg(feature = "alloc")]
     pub use futures_util::future::{
-        abortable, Abortable, AbortHandle, AbortRegistration, Aborted,
+        abortable, Abortable, AbortHandle, AbortRegistration, Aborted, AbortedWith,
+    };
+
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "std")]
+    pub use futures_util::future::{
+        CancellationToken, Cancelled,
     };
 
     #[cfg(feature = "std")]
@@ -403,41 +417,62 @@ pub mod stream {
         once, Once,
         poll_fn, PollFn,
         select, Select,
+        select_with_strategy, PollNext, SelectWithStrategy,
         unfold, Unfold,
 
         StreamExt,
-        Chain, Collect, Concat, Enumerate, Filter, FilterMap, Flatten, Fold,
-        Forward, ForEach, Fuse, StreamFuture, Inspect, Map, Next,
-        SelectNextSome, Peekable, Skip, SkipWhile, Take, TakeWhile,
+        All, Any, Chain, Collect, Concat, Count, Cycle, Dedup, DedupByKey,
+        Enumerate, Filter, FilterMap, Find, FlatMap, Flatten, Fold, Forward,
+        ForEach, Fuse, StreamFuture, Inspect, Intersperse, Last, Map, MaxByKey,
+        MinByKey, Next, SelectNextSome, Pairwise, Peekable, Position, Reduce,
+        Scan, Skip, SkipUntil, SkipWhile, StepBy, Take, TakeUntil, TakeWhile,
         Then, Zip
     };
 
     #[cfg(feature = "alloc")]
     pub use futures_util::stream::{
         // For StreamExt:
-        Chunks,
+        Chunks, ReadyChunks, Windows,
     };
 
+    #[cfg(feature = "alloc")]
+    pub use futures_util::stream::StreamMap;
+
     #[cfg_attr(
         feature = "cfg-target-has-atomic",
         cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
     )]
     #[cfg(feature = "alloc")]
     pub use futures_util::stream::{
+        abortable, Abortable, AbortHandle, AbortRegistration, Aborted, AbortedWith,
+
         FuturesOrdered,
         futures_unordered, FuturesUnordered,
 
         // For StreamExt:
-        BufferUnordered, Buffered, ForEachConcurrent, SplitStream, SplitSink,
+        BufferUnordered, Buffered, FlatMapUnordered, FlattenUnordered,
+        ForEachConcurrent, SplitStream, SplitSink,
         ReuniteError,
 
+        merge_sorted_by, MergeSortedBy,
         select_all, SelectAll,
     };
 
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "std")]
+    pub use futures_util::stream::{
+        FuturesMap,
+        AimdController, ConcurrencyLimit,
+    };
+
     #[cfg(feature = "std")]
     pub use futures_util::stream::{
         // For StreamExt:
-        CatchUnwind,
+        CatchUnwind, Shared, DistinctByKey, Partition, UnzipLeft, UnzipRight,
+        GroupBy, Group,
     };
 
     pub use futures_util::try_stream::{
@@ -446,6 +481,8 @@ pub mod stream {
         InspectOk, InspectErr,
         TryNext, TryForEach, TryFilterMap,
         TryCollect, TryFold, TrySkipWhile,
+        TryAll, TryAny, TryCount, TryFind, TryLast, TryMaxByKey, TryMinByKey,
+        TryPosition, TryReduce,
         IntoStream,
     };
 
@@ -486,8 +523,14 @@ pub mod task {
     #[cfg(feature = "std")]
     pub use futures_util::task::noop_waker_ref;
 
+    #[cfg(feature = "std")]
+    pub use futures_core::task::{poll_budget, with_budget, DEFAULT_BUDGET};
+
+    #[cfg(feature = "alloc")]
+    pub use futures_core::task::{SpawnLocation, TaskMetadata};
+
     #[cfg(feature = "alloc")]
-    pub use futures_util::task::{SpawnExt, LocalSpawnExt};
+    pub use futures_util::task::{SpawnExt, LocalSpawnExt, TaskBuilder};
 
     #[cfg_attr(
         feature = "cfg-target-has-atomic",
@@ -501,6 +544,13 @@ pub mod task {
         cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
     )]
     pub use futures_util::task::AtomicWaker;
+
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "std")]
+    pub use futures_util::task::{JoinHandle, JoinError};
 }
 
 // `select!` re-export --------------------------------------
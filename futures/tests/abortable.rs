@@ -1,6 +1,7 @@
-use futures::channel::oneshot;
+use futures::channel::{mpsc, oneshot};
 use futures::executor::block_on;
-use futures::future::{abortable, Aborted, FutureExt};
+use futures::future::{abortable, Abortable, AbortHandle, Aborted, AbortedWith, FutureExt};
+use futures::stream::{self, FusedStream, StreamExt};
 use futures::task::{Context, Poll};
 use futures_test::task::new_count_waker;
 
@@ -37,3 +38,53 @@ fn abortable_resolves() {
 
     assert_eq!(Ok(Ok(())), block_on(abortable_rx));
 }
+
+#[test]
+fn abortable_reports_aborted() {
+    let (_tx, a_rx) = oneshot::channel::<()>();
+    let (abortable_rx, abort_handle) = abortable(a_rx);
+
+    assert!(!abort_handle.is_aborted());
+    assert!(!abortable_rx.is_aborted());
+    abort_handle.abort();
+    assert!(abort_handle.is_aborted());
+    assert!(abortable_rx.is_aborted());
+    assert_eq!(Err(Aborted), block_on(abortable_rx));
+}
+
+#[test]
+fn abortable_with_reason() {
+    let (_tx, a_rx) = oneshot::channel::<()>();
+    let (abort_handle, abort_registration) = AbortHandle::new_pair_with_reason();
+    let abortable_rx = Abortable::new(a_rx, abort_registration);
+
+    abort_handle.abort_with("admin");
+    abort_handle.abort_with("ignored");
+    assert_eq!(Some("admin"), block_on(abortable_rx).unwrap_err().into_reason());
+
+    let (_tx, a_rx) = oneshot::channel::<()>();
+    let (abort_handle, abort_registration) = AbortHandle::<AbortedWith<&str>>::new_pair_with_reason();
+    let abortable_rx = Abortable::new(a_rx, abort_registration);
+
+    abort_handle.abort();
+    assert_eq!(Err(AbortedWith::default()), block_on(abortable_rx));
+}
+
+#[test]
+fn abortable_stream_ends() {
+    let (mut tx, rx) = mpsc::unbounded::<i32>();
+    let (mut abortable_rx, abort_handle) = stream::abortable(rx);
+
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    tx.start_send(1).unwrap();
+    assert_eq!(Poll::Ready(Some(1)), abortable_rx.poll_next_unpin(&mut cx));
+    assert_eq!(Poll::Pending, abortable_rx.poll_next_unpin(&mut cx));
+    assert!(!abortable_rx.is_terminated());
+
+    abort_handle.abort();
+    assert_eq!(counter, 1);
+    tx.start_send(2).unwrap();
+    assert!(abortable_rx.is_terminated());
+    assert_eq!(Poll::Ready(None), abortable_rx.poll_next_unpin(&mut cx));
+}
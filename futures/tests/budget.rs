@@ -0,0 +1,34 @@
+use futures::executor::block_on;
+use futures::future::poll_fn;
+use futures::task::{poll_budget, with_budget, Poll};
+use futures_test::task::new_count_waker;
+use std::task::Context;
+
+#[test]
+fn unlimited_outside_of_with_budget() {
+    block_on(poll_fn(|cx| {
+        for _ in 0..1000 {
+            assert_eq!(poll_budget(cx), Poll::Ready(()));
+        }
+        Poll::Ready(())
+    }));
+}
+
+#[test]
+fn exhausted_budget_wakes_the_task() {
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    with_budget(2, || {
+        assert_eq!(poll_budget(&mut cx), Poll::Ready(()));
+        assert_eq!(poll_budget(&mut cx), Poll::Ready(()));
+        assert_eq!(count, 0);
+        assert_eq!(poll_budget(&mut cx), Poll::Pending);
+        assert_eq!(count, 1);
+
+        // Nested budgets restore the outer one.
+        with_budget(1, || assert_eq!(poll_budget(&mut cx), Poll::Ready(())));
+        assert_eq!(poll_budget(&mut cx), Poll::Pending);
+    });
+    assert_eq!(poll_budget(&mut cx), Poll::Ready(()));
+}
@@ -0,0 +1,64 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{Abortable, Aborted, CancellationToken, FutureExt};
+use futures::task::{Context, Poll};
+use futures_test::task::new_count_waker;
+
+#[test]
+fn cancel_wakes_waiters() {
+    let token = CancellationToken::new();
+    let mut cancelled = token.cancelled();
+
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    assert_eq!(Poll::Pending, cancelled.poll_unpin(&mut cx));
+    assert_eq!(counter, 0);
+
+    token.cancel();
+    assert_eq!(counter, 1);
+    assert!(token.is_cancelled());
+    assert_eq!(Poll::Ready(()), cancelled.poll_unpin(&mut cx));
+}
+
+#[test]
+fn cancel_propagates_to_children_only() {
+    let parent = CancellationToken::new();
+    let child = parent.child_token();
+    let grandchild = child.child_token();
+    let sibling = parent.child_token();
+
+    child.cancel();
+    assert!(!parent.is_cancelled());
+    assert!(child.is_cancelled());
+    assert!(grandchild.is_cancelled());
+    assert!(!sibling.is_cancelled());
+
+    parent.cancel();
+    assert!(sibling.is_cancelled());
+    block_on(sibling.cancelled());
+}
+
+#[test]
+fn child_of_cancelled_token_is_cancelled() {
+    let parent = CancellationToken::new();
+    parent.cancel();
+    assert!(parent.child_token().is_cancelled());
+}
+
+#[test]
+fn cancel_aborts_registered_futures() {
+    let token = CancellationToken::new();
+    let (_tx, rx) = oneshot::channel::<()>();
+    let mut request = Abortable::new(rx, token.child_token().abort_registration());
+
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    assert_eq!(Poll::Pending, request.poll_unpin(&mut cx));
+
+    token.cancel();
+    assert_eq!(counter, 1);
+    assert_eq!(Poll::Ready(Err(Aborted)), request.poll_unpin(&mut cx));
+
+    let late = Abortable::new(futures::future::ready(()), token.abort_registration());
+    assert_eq!(Err(Aborted), block_on(late));
+}
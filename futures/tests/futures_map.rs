@@ -0,0 +1,82 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::stream::{FusedStream, FuturesMap, Stream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[test]
+fn yields_outputs_with_their_keys() {
+    let mut cx = noop_context();
+    let (tx1, rx1) = oneshot::channel::<u32>();
+    let (tx2, rx2) = oneshot::channel::<u32>();
+    let mut map = FuturesMap::new();
+    map.insert("one", rx1);
+    map.insert("two", rx2);
+    assert_eq!(map.size_hint(), (2, Some(2)));
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Pending);
+
+    tx2.send(2).unwrap();
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Ready(Some(("two", Ok(2)))));
+    assert!(!map.contains_key(&"two"));
+    assert_eq!(map.len(), 1);
+
+    tx1.send(1).unwrap();
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Ready(Some(("one", Ok(1)))));
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(map.is_terminated());
+}
+
+#[test]
+fn cancel_drops_the_future() {
+    struct SetOnDrop(Arc<AtomicBool>);
+
+    impl Drop for SetOnDrop {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let mut cx = noop_context();
+    let dropped = Arc::new(AtomicBool::new(false));
+    let guard = SetOnDrop(dropped.clone());
+    let mut map = FuturesMap::new();
+    map.insert(1, future::pending::<()>().map(move |()| drop(guard)).boxed());
+    map.insert(2, future::pending().boxed());
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Pending);
+
+    assert!(map.cancel(&1));
+    assert!(dropped.load(Ordering::SeqCst));
+    assert!(!map.cancel(&1));
+    assert_eq!(map.keys().collect::<Vec<_>>(), vec![&2]);
+
+    assert!(map.cancel(&2));
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn insert_replaces_the_future() {
+    let (tx, rx) = oneshot::channel::<u32>();
+    let mut map = FuturesMap::new();
+    assert!(!map.insert("key", rx.boxed()));
+    assert!(map.insert("key", future::ready(Ok(5)).boxed()));
+    assert_eq!(map.len(), 1);
+
+    // The replaced future has been dropped.
+    assert!(tx.is_canceled());
+    assert_eq!(block_on(map.collect::<Vec<_>>()), vec![("key", Ok(5))]);
+}
+
+#[test]
+fn cancel_after_completion() {
+    let mut map = FuturesMap::new();
+    map.insert(1, future::ready(1));
+    assert_eq!(block_on(map.next()), Some((1, 1)));
+    assert!(!map.cancel(&1));
+
+    // The map can be reused once it is empty.
+    map.insert(2, future::ready(2));
+    assert_eq!(block_on(map.next()), Some((2, 2)));
+}
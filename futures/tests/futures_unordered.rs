@@ -164,6 +164,22 @@ fn iter_mut_len() {
     assert!(iter_mut.next().is_none());
 }
 
+#[test]
+fn iter_inspects_futures() {
+    let stream = vec![future::ready(1), future::ready(2), future::ready(3)]
+        .into_iter()
+        .collect::<FuturesUnordered<_>>();
+
+    let mut iter = stream.iter();
+    assert_eq!(iter.len(), 3);
+    let mut values = iter.by_ref().map(|fut| block_on(fut.clone())).collect::<Vec<_>>();
+    assert_eq!(iter.len(), 0);
+    assert!(iter.next().is_none());
+    values.sort();
+    assert_eq!(values, vec![1, 2, 3]);
+    assert_eq!(stream.len(), 3);
+}
+
 #[test]
 fn futures_not_moved_after_poll() {
     // Future that will be ready after being polled twice,
@@ -0,0 +1,77 @@
+use futures::channel::oneshot;
+use futures::executor::{block_on, LocalPool, ThreadPool};
+use futures::future::{self, FutureExt};
+use futures::task::{LocalSpawnExt, SpawnExt};
+
+#[test]
+fn join_handle_resolves_to_output() {
+    let mut pool = ThreadPool::new().unwrap();
+    let handle = pool.spawn_with_join_handle(future::ready(1)).unwrap();
+    assert_eq!(block_on(handle).unwrap(), 1);
+}
+
+#[test]
+fn join_handle_reports_panic() {
+    let mut pool = LocalPool::new();
+    let mut spawner = pool.spawner();
+    let handle = spawner
+        .spawn_local_with_join_handle(future::lazy(|_| -> () { panic!("boom") }))
+        .unwrap();
+
+    let err = pool.run_until(handle).unwrap_err();
+    assert!(err.is_panic());
+    assert!(!err.is_cancelled());
+    assert_eq!(*err.try_into_panic().unwrap().downcast::<&str>().unwrap(), "boom");
+}
+
+#[test]
+fn join_handle_abort() {
+    let mut pool = LocalPool::new();
+    let mut spawner = pool.spawner();
+    let (_tx, rx) = oneshot::channel::<()>();
+    let handle = spawner.spawn_local_with_join_handle(rx).unwrap();
+
+    pool.run_until_stalled();
+    assert!(!handle.is_finished());
+
+    handle.abort();
+    pool.run_until_stalled();
+    assert!(handle.is_finished());
+    assert!(block_on(handle).unwrap_err().is_cancelled());
+}
+
+#[test]
+fn join_handle_detaches_on_drop() {
+    let mut pool = LocalPool::new();
+    let mut spawner = pool.spawner();
+    let (tx, rx) = oneshot::channel::<()>();
+    let (done_tx, done_rx) = oneshot::channel::<()>();
+    drop(spawner.spawn_local_with_join_handle(rx.map(move |_| done_tx.send(()))).unwrap());
+
+    tx.send(()).unwrap();
+    pool.run_until(done_rx).unwrap();
+}
+
+#[test]
+fn join_handle_abort_on_drop() {
+    let mut pool = LocalPool::new();
+    let mut spawner = pool.spawner();
+    let (tx, rx) = oneshot::channel::<()>();
+    let (done_tx, done_rx) = oneshot::channel::<()>();
+    drop(spawner.spawn_local_with_join_handle(rx.map(move |_| done_tx.send(()))).unwrap().abort_on_drop());
+
+    pool.run_until_stalled();
+    assert!(tx.send(()).is_err());
+    assert!(pool.run_until(done_rx).is_err());
+}
+
+#[test]
+fn join_handle_cancelled_when_executor_drops_task() {
+    let pool = LocalPool::new();
+    let mut spawner = pool.spawner();
+    let handle = spawner.spawn_local_with_join_handle(future::pending::<()>()).unwrap();
+
+    drop(pool);
+    assert!(handle.is_finished());
+    assert!(block_on(handle).unwrap_err().is_cancelled());
+}
@@ -1,7 +1,7 @@
 #![feature(async_await)]
 
 use futures::executor::block_on;
-use futures::stream;
+use futures::stream::{self, Stream};
 use futures_util::StreamExt;
 
 #[test]
@@ -17,3 +17,437 @@ fn select() {
     select_and_compare(vec![1, 2, 3], vec![4, 5], vec![1, 4, 2, 5, 3]);
     select_and_compare(vec![1, 2], vec![4, 5, 6], vec![1, 4, 2, 5, 6]);
 }
+
+#[test]
+fn select_with_strategy() {
+    use futures::stream::PollNext;
+
+    let biased = stream::select_with_strategy(
+        stream::iter(vec![1, 2, 3]),
+        stream::iter(vec![4, 5]),
+        |_: &mut ()| PollNext::Left,
+    );
+    assert_eq!(block_on(biased.collect::<Vec<_>>()), vec![1, 2, 3, 4, 5]);
+
+    let round_robin = stream::select_with_strategy(
+        stream::iter(vec![1, 2, 3]),
+        stream::iter(vec![4, 5]),
+        PollNext::toggle,
+    );
+    assert_eq!(block_on(round_robin.collect::<Vec<_>>()), vec![1, 4, 2, 5, 3]);
+
+    let weighted = stream::select_with_strategy(
+        stream::repeat(1),
+        stream::repeat(2),
+        |polls: &mut u32| {
+            *polls = (*polls + 1) % 3;
+            if *polls == 0 { PollNext::Right } else { PollNext::Left }
+        },
+    );
+    assert_eq!(block_on(weighted.take(6).collect::<Vec<_>>()), vec![1, 1, 2, 1, 1, 2]);
+}
+
+#[test]
+fn size_hint() {
+    assert_eq!(stream::iter(1..=5).size_hint(), (5, Some(5)));
+    assert_eq!(stream::empty::<()>().size_hint(), (0, Some(0)));
+    assert_eq!(stream::once(futures::future::ready(1)).size_hint(), (1, Some(1)));
+    assert_eq!(stream::repeat(1).size_hint(), (usize::max_value(), None));
+
+    let s = || stream::iter(1..=5);
+    assert_eq!(s().map(|x| x * 2).size_hint(), (5, Some(5)));
+    assert_eq!(s().enumerate().size_hint(), (5, Some(5)));
+    assert_eq!(s().filter(|_| futures::future::ready(true)).size_hint(), (0, Some(5)));
+    assert_eq!(s().take(3).size_hint(), (3, Some(3)));
+    assert_eq!(s().take(10).size_hint(), (5, Some(5)));
+    assert_eq!(s().skip(2).size_hint(), (3, Some(3)));
+    assert_eq!(s().skip(10).size_hint(), (0, Some(0)));
+    assert_eq!(s().chain(s()).size_hint(), (10, Some(10)));
+    assert_eq!(s().zip(stream::iter(1..=3)).size_hint(), (3, Some(3)));
+    assert_eq!(s().zip(stream::repeat(1)).size_hint(), (5, Some(5)));
+    assert_eq!(s().chunks(2).size_hint(), (3, Some(3)));
+    assert_eq!(s().ready_chunks(2).size_hint(), (3, Some(5)));
+    assert_eq!(s().windows(2).size_hint(), (4, Some(4)));
+    assert_eq!(s().windows(10).size_hint(), (0, Some(0)));
+    assert_eq!(s().pairwise().size_hint(), (4, Some(4)));
+    assert_eq!(s().dedup().size_hint(), (1, Some(5)));
+    assert_eq!(stream::select(s(), s()).size_hint(), (10, Some(10)));
+    assert_eq!(s().map(futures::future::ready).buffered(2).size_hint(), (5, Some(5)));
+    assert_eq!(s().step_by(2).size_hint(), (3, Some(3)));
+    assert_eq!(s().intersperse(0).size_hint(), (9, Some(9)));
+    assert_eq!(s().cycle().size_hint(), (usize::max_value(), None));
+    assert_eq!(stream::empty::<()>().cycle().size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn size_hint_tracks_progress() {
+    let mut s = stream::iter(1..=5).fuse();
+    block_on(s.next());
+    assert_eq!(s.size_hint(), (4, Some(4)));
+    assert_eq!(block_on((&mut s).collect::<Vec<_>>()), vec![2, 3, 4, 5]);
+    assert_eq!(s.size_hint(), (0, Some(0)));
+
+    let mut s = stream::iter(1..=5).chunks(2);
+    assert_eq!(block_on(s.next()), Some(vec![1, 2]));
+    assert_eq!(s.size_hint(), (2, Some(2)));
+
+    let mut s = stream::iter(1..=5).peekable();
+    block_on(futures::future::poll_fn(|cx| {
+        let _ = std::pin::Pin::new(&mut s).peek(cx);
+        std::task::Poll::Ready(())
+    }));
+    assert_eq!(s.size_hint(), (5, Some(5)));
+}
+
+#[test]
+fn concat_passes_size_hints_on() {
+    // Records the size hints it is extended with.
+    #[derive(Default)]
+    struct Recorder {
+        items: Vec<u32>,
+        hints: Vec<(usize, Option<usize>)>,
+    }
+
+    impl Extend<u32> for Recorder {
+        fn extend<I: IntoIterator<Item = u32>>(&mut self, iter: I) {
+            let mut iter = iter.into_iter();
+            while let Some(item) = iter.next() {
+                self.items.push(item);
+                self.hints.push(iter.size_hint());
+            }
+        }
+    }
+
+    impl IntoIterator for Recorder {
+        type Item = u32;
+        type IntoIter = std::vec::IntoIter<u32>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.items.into_iter()
+        }
+    }
+
+    let recorder = |items: Vec<u32>| Recorder { items, hints: Vec::new() };
+    let s = stream::iter(vec![recorder(vec![1]), recorder(vec![2, 3]), recorder(vec![4, 5, 6])]);
+    let concat = block_on(s.concat());
+    assert_eq!(concat.items, vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(concat.hints, vec![(1, None), (0, None), (2, Some(2)), (1, Some(1)), (0, Some(0))]);
+}
+
+#[test]
+fn ready_chunks() {
+    use futures::channel::mpsc;
+    use futures::stream::FusedStream;
+    use futures_test::task::noop_context;
+    use std::task::Poll;
+
+    let mut cx = noop_context();
+    let (tx, rx) = mpsc::unbounded();
+    let mut s = rx.ready_chunks(3);
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Pending);
+
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(vec![1])));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Pending);
+
+    for i in 2..=6 {
+        tx.unbounded_send(i).unwrap();
+    }
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(vec![2, 3, 4])));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(vec![5, 6])));
+
+    tx.unbounded_send(7).unwrap();
+    drop(tx);
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(Some(vec![7])));
+    assert_eq!(s.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(s.is_terminated());
+}
+
+#[test]
+fn windows() {
+    let stream = stream::iter(1..=5).windows(3);
+    assert_eq!(
+        block_on(stream.collect::<Vec<_>>()),
+        vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]],
+    );
+
+    let mut stream = stream::iter(1..=5).windows(2);
+    assert_eq!(block_on(stream.next()), Some(vec![1, 2]));
+    assert_eq!(stream.size_hint(), (3, Some(3)));
+
+    let stream = stream::iter(1..=2).windows(3);
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), Vec::<Vec<i32>>::new());
+}
+
+#[test]
+fn pairwise() {
+    let stream = stream::iter(1..=4).pairwise();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![(1, 2), (2, 3), (3, 4)]);
+
+    let stream = stream::once(futures::future::ready(1)).pairwise();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![]);
+}
+
+#[test]
+fn dedup() {
+    let stream = stream::iter(vec![1, 1, 2, 2, 2, 1, 3, 3]).dedup();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2, 1, 3]);
+
+    let stream = stream::iter(vec!["a", "A", "b", "B", "a"])
+        .dedup_by_key(|s| s.to_lowercase());
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec!["a", "b", "a"]);
+}
+
+#[test]
+fn distinct_by_key() {
+    let stream = stream::iter(vec![1, 2, 1, 3, 2, 4, 1]).distinct_by_key(3, |x| *x);
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2, 3, 4, 1]);
+
+    // Seeing a key again keeps it from being evicted.
+    let stream = stream::iter(vec![1, 2, 1, 3, 1, 2]).distinct_by_key(2, |x| *x);
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2, 3, 2]);
+}
+
+#[test]
+fn collect_reserves_capacity() {
+    let v = block_on(stream::iter(0..100).map(|x| x + 1).collect::<Vec<_>>());
+    assert_eq!(v.len(), 100);
+    assert_eq!(v.capacity(), 100);
+}
+
+#[test]
+fn scan() {
+    let stream = stream::iter(1..=10).scan(1, |product, x| {
+        *product *= x;
+        futures::future::ready(if x <= 4 { Some(*product) } else { None })
+    }).fuse();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2, 6, 24]);
+}
+
+#[test]
+fn flat_map() {
+    let stream = stream::iter(vec![2, 0, 3]).flat_map(|n| stream::iter(0..n));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![0, 1, 0, 1, 2]);
+}
+
+#[test]
+fn flatten_unordered() {
+    use futures::channel::mpsc;
+    use futures::stream::FusedStream;
+    use futures_test::task::noop_context;
+    use std::task::Poll;
+
+    let mut cx = noop_context();
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded();
+    let (tx3, rx3) = mpsc::unbounded();
+    let mut stream = stream::iter(vec![rx1, rx2, rx3]).flatten_unordered(2);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // Only the first two inner streams are polled.
+    tx3.unbounded_send(3).unwrap();
+    tx2.unbounded_send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // Finishing one of them makes room for the third.
+    drop(tx1);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+
+    tx2.unbounded_send(4).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(4)));
+
+    drop((tx2, tx3));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn flat_map_unordered() {
+    let stream = stream::iter(vec![2, 0, 3])
+        .flat_map_unordered(1, |n| stream::iter(0..n));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![0, 1, 0, 1, 2]);
+
+    let stream = stream::iter(vec![2, 0, 3])
+        .flat_map_unordered(3, |n| stream::iter(0..n));
+    let mut values = block_on(stream.collect::<Vec<_>>());
+    values.sort();
+    assert_eq!(values, vec![0, 0, 1, 1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn flatten_unordered_zero_limit() {
+    let _ = stream::iter(vec![stream::iter(0..1)]).flatten_unordered(0);
+}
+
+#[test]
+#[should_panic]
+fn flat_map_unordered_zero_limit() {
+    let _ = stream::iter(0..1).flat_map_unordered(0, |n| stream::iter(0..n));
+}
+
+#[test]
+fn take_until() {
+    use futures::channel::oneshot;
+    use futures::stream::FusedStream;
+    use futures_test::task::noop_context;
+    use std::task::Poll;
+
+    let mut cx = noop_context();
+    let (tx, rx) = oneshot::channel::<()>();
+    let mut stream = stream::repeat(1).take_until(rx);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+
+    tx.send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+
+    // The stream also ends when the underlying stream does.
+    let stream = stream::iter(1..=3).take_until(futures::future::pending::<()>());
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2, 3]);
+}
+
+#[test]
+fn skip_until() {
+    use futures::channel::{mpsc, oneshot};
+    use futures::stream::FusedStream;
+    use futures_test::task::noop_context;
+    use std::task::Poll;
+
+    let mut cx = noop_context();
+    let (item_tx, item_rx) = mpsc::unbounded();
+    let (tx, rx) = oneshot::channel::<()>();
+    let mut stream = item_rx.skip_until(rx);
+
+    item_tx.unbounded_send(1).unwrap();
+    item_tx.unbounded_send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+
+    tx.send(()).unwrap();
+    item_tx.unbounded_send(3).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+    drop(item_tx);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn cycle() {
+    let stream = stream::iter(vec![1, 2, 3]).cycle().take(7);
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2, 3, 1, 2, 3, 1]);
+
+    let stream = stream::iter(Vec::<u32>::new()).cycle();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![]);
+}
+
+#[test]
+fn step_by() {
+    let stream = stream::iter(0..10).step_by(3);
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![0, 3, 6, 9]);
+
+    let mut stream = stream::iter(0..10).step_by(3);
+    block_on(stream.next());
+    assert_eq!(stream.size_hint(), (3, Some(3)));
+}
+
+#[test]
+fn intersperse() {
+    let stream = stream::iter(vec![1, 2, 3]).intersperse(0);
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 0, 2, 0, 3]);
+
+    let stream = stream::iter(vec![1]).intersperse(0);
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1]);
+
+    let mut stream = stream::iter(vec![1, 2, 3]).intersperse(0);
+    block_on(stream.next());
+    assert_eq!(stream.size_hint(), (4, Some(4)));
+    block_on(stream.next());
+    assert_eq!(stream.size_hint(), (3, Some(3)));
+}
+
+#[test]
+fn count_and_last() {
+    assert_eq!(block_on(stream::iter(1..=5).count()), 5);
+    assert_eq!(block_on(stream::iter(1..=5).last()), Some(5));
+    assert_eq!(block_on(stream::empty::<i32>().last()), None);
+}
+
+#[test]
+fn any_and_all_short_circuit() {
+    use futures::future::ready;
+
+    let mut s = stream::iter(1..=5);
+    assert!(block_on((&mut s).any(|x| ready(x == 2))));
+    assert_eq!(block_on(s.next()), Some(3));
+    assert!(!block_on(stream::iter(1..=5).any(|x| ready(x > 5))));
+
+    let mut s = stream::iter(1..=5);
+    assert!(!block_on((&mut s).all(|x| ready(x < 3))));
+    assert_eq!(block_on(s.next()), Some(4));
+    assert!(block_on(stream::iter(1..=5).all(|x| ready(x <= 5))));
+}
+
+#[test]
+fn find_and_position() {
+    use futures::future::ready;
+
+    let mut s = stream::iter(1..=5);
+    assert_eq!(block_on((&mut s).find(|x| ready(*x > 2))), Some(3));
+    assert_eq!(block_on(s.next()), Some(4));
+    assert_eq!(block_on(stream::iter(1..=5).find(|x| ready(*x > 5))), None);
+
+    let mut s = stream::iter(1..=5);
+    assert_eq!(block_on((&mut s).position(|x| ready(x > 2))), Some(2));
+    assert_eq!(block_on(s.next()), Some(4));
+    assert_eq!(block_on(stream::iter(1..=5).position(|x| ready(x > 5))), None);
+}
+
+#[test]
+fn min_and_max_by_key() {
+    let s = || stream::iter(vec![(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')]);
+    assert_eq!(block_on(s().min_by_key(|x| x.0)), Some((0, 'b')));
+    assert_eq!(block_on(s().max_by_key(|x| x.0)), Some((1, 'c')));
+    assert_eq!(block_on(stream::empty::<i32>().max_by_key(|x| *x)), None);
+}
+
+#[test]
+fn reduce() {
+    use futures::future::ready;
+
+    assert_eq!(block_on(stream::iter(1..=4).reduce(|a, b| ready(a + b))), Some(10));
+    assert_eq!(block_on(stream::iter(1..=1).reduce(|a, b| ready(a + b))), Some(1));
+    assert_eq!(block_on(stream::empty::<i32>().reduce(|a, b| ready(a + b))), None);
+}
+
+#[test]
+fn try_aggregators_short_circuit_on_error() {
+    use futures::future::ready;
+    use futures::stream::TryStreamExt;
+
+    let s = || stream::iter(vec![Ok(1), Ok(2), Err("error"), Ok(3)]);
+    assert_eq!(block_on(s().try_count()), Err("error"));
+    assert_eq!(block_on(s().try_last()), Err("error"));
+    assert_eq!(block_on(s().try_min_by_key(|x| *x)), Err("error"));
+    assert_eq!(block_on(s().try_max_by_key(|x| *x)), Err("error"));
+    assert_eq!(block_on(s().try_reduce(|a, b| ready(Ok(a + b)))), Err("error"));
+    assert_eq!(block_on(s().try_any(|x| ready(x == 3))), Err("error"));
+    assert_eq!(block_on(s().try_all(|x| ready(x < 3))), Err("error"));
+    assert_eq!(block_on(s().try_find(|x| ready(*x == 3))), Err("error"));
+    assert_eq!(block_on(s().try_position(|x| ready(x == 3))), Err("error"));
+
+    // A match before the error wins.
+    assert_eq!(block_on(s().try_any(|x| ready(x == 2))), Ok(true));
+    assert_eq!(block_on(s().try_all(|x| ready(x < 2))), Ok(false));
+    assert_eq!(block_on(s().try_find(|x| ready(*x == 2))), Ok(Some(2)));
+    assert_eq!(block_on(s().try_position(|x| ready(x == 2))), Ok(Some(1)));
+
+    let s = || stream::iter(vec![Ok::<i32, ()>(3), Ok(1), Ok(2)]);
+    assert_eq!(block_on(s().try_count()), Ok(3));
+    assert_eq!(block_on(s().try_last()), Ok(Some(2)));
+    assert_eq!(block_on(s().try_min_by_key(|x| *x)), Ok(Some(1)));
+    assert_eq!(block_on(s().try_max_by_key(|x| *x)), Ok(Some(3)));
+    assert_eq!(block_on(s().try_reduce(|a, b| ready(Ok(a * b)))), Ok(Some(6)));
+    assert_eq!(block_on(s().try_reduce(|_, _| ready(Err(())))), Err(()));
+}
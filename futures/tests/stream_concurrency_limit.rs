@@ -0,0 +1,150 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future;
+use futures::stream::{self, AimdController, ConcurrencyLimit, StreamExt};
+use futures::task::{Context, Poll};
+use futures_test::task::{new_count_waker, noop_context};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[test]
+fn buffer_unordered_respects_limit() {
+    let mut cx = noop_context();
+    let limit = ConcurrencyLimit::new(2);
+    let started = AtomicUsize::new(0);
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..4).map(|_| oneshot::channel::<i32>()).unzip();
+
+    let mut stream = stream::iter(rxs)
+        .map(|rx| {
+            started.fetch_add(1, Ordering::SeqCst);
+            rx
+        })
+        .buffer_unordered_with_limit(limit.clone());
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(started.load(Ordering::SeqCst), 2);
+
+    let mut txs = txs.into_iter();
+    txs.next().unwrap().send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(started.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn raising_limit_wakes_task() {
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let limit = ConcurrencyLimit::new(0);
+
+    let mut stream = stream::iter(vec![future::ready(1), future::ready(2)])
+        .buffered_with_limit(limit.clone());
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(count, 0);
+
+    limit.set(1);
+    assert_eq!(count, 1);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn lowering_limit_does_not_wake_task() {
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let limit = ConcurrencyLimit::new(0);
+
+    let mut stream = stream::iter(vec![future::ready(1)])
+        .buffer_unordered_with_limit(limit.clone());
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    limit.set(0);
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn for_each_concurrent_with_limit() {
+    let limit = ConcurrencyLimit::new(3);
+    let sum = AtomicUsize::new(0);
+    block_on(stream::iter(1..=10).for_each_concurrent_with_limit(limit, |x| {
+        sum.fetch_add(x, Ordering::SeqCst);
+        future::ready(())
+    }));
+    assert_eq!(sum.load(Ordering::SeqCst), 55);
+}
+
+#[test]
+fn aimd_controller_stays_in_bounds() {
+    let limit = ConcurrencyLimit::new(100);
+    let controller = AimdController::new(limit.clone(), 2, 8);
+    assert_eq!(limit.get(), 8);
+
+    for _ in 0..16 {
+        controller.on_success(Duration::from_millis(1));
+    }
+    assert_eq!(limit.get(), 8);
+
+    for _ in 0..10 {
+        controller.on_error(Duration::from_secs(0));
+    }
+    assert_eq!(limit.get(), 2);
+}
+
+#[test]
+fn aimd_controller_increases_once_per_window() {
+    let limit = ConcurrencyLimit::new(4);
+    let controller = AimdController::new(limit.clone(), 1, 100);
+
+    for _ in 0..3 {
+        controller.on_success(Duration::from_millis(1));
+    }
+    assert_eq!(limit.get(), 4);
+    controller.on_success(Duration::from_millis(1));
+    assert_eq!(limit.get(), 5);
+
+    for _ in 0..4 {
+        controller.on_success(Duration::from_millis(1));
+    }
+    assert_eq!(limit.get(), 5);
+    controller.on_success(Duration::from_millis(1));
+    assert_eq!(limit.get(), 6);
+}
+
+#[test]
+fn aimd_controller_decreases_once_per_burst() {
+    let limit = ConcurrencyLimit::new(16);
+    let controller = AimdController::new(limit.clone(), 1, 100);
+
+    // Operations which were all running when the first one failed.
+    for _ in 0..5 {
+        controller.on_error(Duration::from_secs(1));
+    }
+    assert_eq!(limit.get(), 8);
+
+    // An operation started after the decrease.
+    controller.on_error(Duration::from_secs(0));
+    assert_eq!(limit.get(), 4);
+}
+
+#[test]
+fn aimd_controller_backs_off_on_slow_success() {
+    let limit = ConcurrencyLimit::new(10);
+    let controller = AimdController::new(limit.clone(), 1, 100)
+        .backoff_ratio(0.8)
+        .latency_threshold(Duration::from_millis(50));
+
+    controller.on_success(Duration::from_millis(10));
+    assert_eq!(limit.get(), 10);
+
+    controller.on_success(Duration::from_millis(60));
+    assert_eq!(limit.get(), 8);
+    assert_eq!(controller.limit().get(), 8);
+}
+
+#[test]
+#[should_panic]
+fn aimd_controller_rejects_zero_minimum() {
+    AimdController::new(ConcurrencyLimit::new(1), 0, 1);
+}
@@ -0,0 +1,109 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::stream::{self, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn partition() {
+    let (evens, odds) = stream::iter(1..=10).partition(10, |x| x % 2 == 0);
+    let (evens, odds) = block_on(future::join(
+        evens.collect::<Vec<_>>(),
+        odds.collect::<Vec<_>>(),
+    ));
+    assert_eq!(evens, vec![2, 4, 6, 8, 10]);
+    assert_eq!(odds, vec![1, 3, 5, 7, 9]);
+}
+
+#[test]
+fn partition_applies_backpressure() {
+    let mut cx = noop_context();
+    let (mut evens, mut odds) = stream::iter(vec![1, 3, 5, 2]).partition(2, |x| x % 2 == 0);
+
+    // Two odd items fill the buffer of `odds`.
+    assert_eq!(evens.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(odds.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(evens.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(odds.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+    assert_eq!(odds.poll_next_unpin(&mut cx), Poll::Ready(Some(5)));
+    assert_eq!(evens.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(evens.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert_eq!(odds.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn partition_discards_items_of_dropped_half() {
+    let (evens, odds) = stream::iter(1..=10).partition(1, |x| x % 2 == 0);
+    drop(odds);
+    assert_eq!(block_on(evens.collect::<Vec<_>>()), vec![2, 4, 6, 8, 10]);
+}
+
+#[test]
+fn unzip() {
+    let (tx, rx) = mpsc::unbounded();
+    let (mut left, mut right) = rx.unzip(1);
+    let mut cx = noop_context();
+
+    tx.unbounded_send((1, 'a')).unwrap();
+    tx.unbounded_send((2, 'b')).unwrap();
+    assert_eq!(right.poll_next_unpin(&mut cx), Poll::Ready(Some('a')));
+    // The first element of the second pair has no space yet.
+    assert_eq!(right.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(left.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(left.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(right.poll_next_unpin(&mut cx), Poll::Ready(Some('b')));
+
+    drop(tx);
+    assert_eq!(left.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert_eq!(right.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn group_by() {
+    let groups = stream::iter(vec![1, 1, 2, 3, 3, 3, 1]).group_by(4, |x| *x);
+    let groups = block_on(groups.then(|(key, group)| {
+        group.collect::<Vec<_>>().map(move |items| (key, items))
+    }).collect::<Vec<_>>());
+    assert_eq!(groups, vec![
+        (1, vec![1, 1]),
+        (2, vec![2]),
+        (3, vec![3, 3, 3]),
+        (1, vec![1]),
+    ]);
+}
+
+#[test]
+fn group_by_buffers_unread_groups() {
+    let mut groups = stream::iter(vec![1, 1, 2, 2, 2]).group_by(3, |x| *x);
+    let mut cx = noop_context();
+
+    let (key, mut first) = match groups.poll_next_unpin(&mut cx) {
+        Poll::Ready(Some(group)) => group,
+        _ => panic!(),
+    };
+    assert_eq!(key, 1);
+    let (key, mut second) = match groups.poll_next_unpin(&mut cx) {
+        Poll::Ready(Some(group)) => group,
+        _ => panic!(),
+    };
+    assert_eq!(key, 2);
+
+    // The second group is full, so the end of the stream can't be seen yet.
+    assert!(groups.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(second.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    match groups.poll_next_unpin(&mut cx) {
+        Poll::Ready(None) => {}
+        _ => panic!(),
+    }
+
+    assert_eq!(block_on(first.by_ref().collect::<Vec<_>>()), vec![1, 1]);
+    assert_eq!(block_on(second.collect::<Vec<_>>()), vec![2, 2]);
+}
+
+#[test]
+fn group_by_discards_items_of_dropped_groups() {
+    let groups = stream::iter(vec![1, 1, 1, 2, 3, 3]).group_by(1, |x| *x);
+    let keys = block_on(groups.map(|(key, _group)| key).collect::<Vec<_>>());
+    assert_eq!(keys, vec![1, 2, 3]);
+}
@@ -0,0 +1,76 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, FusedStream, Stream, StreamExt, StreamMap};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn yields_items_with_their_keys() {
+    let mut map = StreamMap::new();
+    map.insert(1, stream::iter(vec!['a', 'b']));
+    map.insert(2, stream::iter(vec!['c']));
+    assert_eq!(map.size_hint(), (5, Some(5)));
+
+    let mut events = block_on(map.collect::<Vec<_>>());
+    events.sort();
+    assert_eq!(events, vec![
+        (1, None), (1, Some('a')), (1, Some('b')),
+        (2, None), (2, Some('c')),
+    ]);
+}
+
+#[test]
+fn reports_the_end_of_each_stream() {
+    let mut cx = noop_context();
+    let (tx1, rx1) = mpsc::unbounded::<u32>();
+    let (tx2, rx2) = mpsc::unbounded::<u32>();
+    let mut map = StreamMap::new();
+    map.insert("one", rx1);
+    map.insert("two", rx2);
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Pending);
+
+    drop(tx1);
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Ready(Some(("one", None))));
+    assert!(!map.contains_key(&"one"));
+    assert_eq!(map.len(), 1);
+
+    tx2.unbounded_send(7).unwrap();
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Ready(Some(("two", Some(7)))));
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Pending);
+}
+
+#[test]
+fn insert_and_remove() {
+    let mut cx = noop_context();
+    let mut map = StreamMap::new();
+    assert!(map.insert("a", stream::iter(vec![1])).is_none());
+    assert!(map.insert("b", stream::iter(vec![2])).is_none());
+
+    // Replacing a stream returns the old one.
+    let old = map.insert("a", stream::iter(vec![3])).unwrap();
+    assert_eq!(block_on(old.collect::<Vec<_>>()), vec![1]);
+
+    let removed = map.remove(&"b").unwrap();
+    assert_eq!(block_on(removed.collect::<Vec<_>>()), vec![2]);
+    assert!(map.remove(&"b").is_none());
+    assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"a"]);
+
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Ready(Some(("a", Some(3)))));
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Ready(Some(("a", None))));
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(map.is_terminated());
+
+    map.insert("c", stream::iter(vec![4]));
+    assert!(!map.is_terminated());
+    assert_eq!(map.poll_next_unpin(&mut cx), Poll::Ready(Some(("c", Some(4)))));
+}
+
+#[test]
+fn busy_streams_do_not_starve_others() {
+    let mut map = StreamMap::new();
+    map.insert(1, stream::repeat(1).boxed());
+    map.insert(2, stream::repeat(2).boxed());
+    let keys = block_on(map.take(4).map(|(key, _)| key).collect::<Vec<_>>());
+    assert!(keys.contains(&1));
+    assert!(keys.contains(&2));
+}
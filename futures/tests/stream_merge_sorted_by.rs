@@ -0,0 +1,72 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, FusedStream, MergeSortedBy, Stream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn merges_sorted_streams() {
+    let merged = stream::merge_sorted_by(
+        vec![
+            stream::iter(vec![1, 3, 5, 9]),
+            stream::iter(vec![2, 3, 10]),
+            stream::iter(vec![]),
+            stream::iter(vec![0]),
+        ],
+        |a: &i32, b: &i32| a.cmp(b),
+    );
+    assert_eq!(merged.size_hint(), (8, Some(8)));
+    assert_eq!(block_on(merged.collect::<Vec<_>>()), vec![0, 1, 2, 3, 3, 5, 9, 10]);
+}
+
+#[test]
+fn equal_items_keep_input_order() {
+    let merged = stream::merge_sorted_by(
+        vec![
+            stream::iter(vec![(1, 'a'), (2, 'a')]),
+            stream::iter(vec![(1, 'b'), (2, 'b')]),
+        ],
+        |a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0),
+    );
+    assert_eq!(
+        block_on(merged.collect::<Vec<_>>()),
+        vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')],
+    );
+}
+
+#[test]
+fn waits_for_pending_inputs() {
+    let mut cx = noop_context();
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded();
+    let mut merged = stream::merge_sorted_by(vec![rx1, rx2], |a: &u32, b: &u32| a.cmp(b));
+
+    tx1.unbounded_send(5).unwrap();
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Pending);
+
+    tx2.unbounded_send(3).unwrap();
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+    // The second input could still yield an item less than 5.
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Pending);
+
+    drop(tx2);
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(5)));
+    drop(tx1);
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(merged.is_terminated());
+}
+
+#[test]
+fn push_inputs_dynamically() {
+    let mut cx = noop_context();
+    let mut merged = MergeSortedBy::new(|a: &i32, b: &i32| a.cmp(b));
+    assert!(merged.is_empty());
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(merged.is_terminated());
+
+    merged.push(stream::iter(vec![2, 4]));
+    merged.push(stream::iter(vec![1, 3]));
+    assert_eq!(merged.len(), 2);
+    assert!(!merged.is_terminated());
+    assert_eq!(block_on(merged.collect::<Vec<_>>()), vec![1, 2, 3, 4]);
+}
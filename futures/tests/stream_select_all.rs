@@ -2,7 +2,7 @@
 
 use futures::executor::block_on_stream;
 use futures::future::{self, FutureExt};
-use futures::stream::{self, FusedStream, SelectAll, StreamExt};
+use futures::stream::{self, BoxStream, FusedStream, SelectAll, StreamExt};
 use futures::task::Poll;
 use futures_test::task::noop_context;
 
@@ -48,3 +48,57 @@ fn issue_1626() {
     assert_eq!(s.next(), Some(14));
     assert_eq!(s.next(), None);
 }
+
+#[test]
+fn higher_priority_streams_go_first() {
+    let mut set = SelectAll::new();
+    set.push(stream::iter(vec![1, 2]));
+    set.push_with_priority(stream::iter(vec![10, 11]), 2);
+    set.push_with_priority(stream::iter(vec![5]), 1);
+    assert_eq!(set.len(), 3);
+
+    let mut s = block_on_stream(set);
+    assert_eq!(s.next(), Some(10));
+    assert_eq!(s.next(), Some(11));
+    assert_eq!(s.next(), Some(5));
+    assert_eq!(s.next(), Some(1));
+    assert_eq!(s.next(), Some(2));
+    assert_eq!(s.next(), None);
+}
+
+#[test]
+fn lower_priority_streams_run_while_higher_ones_are_pending() {
+    let mut cx = noop_context();
+    let (tx, rx) = futures::channel::mpsc::unbounded();
+    let mut set = SelectAll::<BoxStream<'static, i32>>::new();
+    set.push(stream::iter(vec![1]).boxed());
+    set.push_with_priority(rx.boxed(), 1);
+
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Pending);
+    tx.unbounded_send(2).unwrap();
+    drop(tx);
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(set.is_terminated());
+}
+
+#[test]
+fn priorities_can_be_reused_after_their_streams_end() {
+    let mut cx = noop_context();
+    let mut set = SelectAll::new();
+    set.push(stream::iter(vec![1]));
+    set.push_with_priority(stream::iter(vec![10]), 1);
+
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some(10)));
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(set.is_empty());
+
+    set.push(stream::iter(vec![2]));
+    set.push_with_priority(stream::iter(vec![20]), 1);
+    assert_eq!(set.len(), 2);
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some(20)));
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(set.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
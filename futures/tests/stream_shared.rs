@@ -0,0 +1,76 @@
+use futures::channel::mpsc;
+use futures::executor::{block_on, block_on_stream};
+use futures::future;
+use futures::stream::{self, Stream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::thread;
+
+#[test]
+fn every_clone_receives_every_item() {
+    let a = stream::iter(1..=5).shared(1);
+    let b = a.clone();
+    let c = b.clone();
+    let (a, b, c) = block_on(future::join3(
+        a.collect::<Vec<_>>(),
+        b.collect::<Vec<_>>(),
+        c.collect::<Vec<_>>(),
+    ));
+    assert_eq!(a, vec![1, 2, 3, 4, 5]);
+    assert_eq!(b, a);
+    assert_eq!(c, a);
+}
+
+#[test]
+fn slowest_clone_sets_the_pace() {
+    let mut cx = noop_context();
+    let mut a = stream::iter(1..=5).shared(2);
+    let mut b = a.clone();
+
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(a.size_hint(), (3, Some(3)));
+    assert_eq!(b.size_hint(), (5, Some(5)));
+
+    assert_eq!(b.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // Dropping the slow clone lets the other one run ahead.
+    drop(b);
+    assert_eq!(block_on(a.collect::<Vec<_>>()), vec![4, 5]);
+}
+
+#[test]
+fn clones_start_where_they_were_cloned() {
+    let mut cx = noop_context();
+    let mut a = stream::iter(1..=3).shared(4);
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+
+    let b = a.clone();
+    assert_eq!(block_on(a.collect::<Vec<_>>()), vec![2, 3]);
+    assert_eq!(block_on(b.collect::<Vec<_>>()), vec![2, 3]);
+}
+
+#[test]
+fn consumers_on_other_threads() {
+    let (tx, rx) = mpsc::unbounded();
+    let shared = rx.shared(4);
+    let handles = (0..3)
+        .map(|_| {
+            let stream = shared.clone();
+            thread::spawn(move || block_on_stream(stream).collect::<Vec<_>>())
+        })
+        .collect::<Vec<_>>();
+    drop(shared);
+
+    for i in 0..100 {
+        tx.unbounded_send(i).unwrap();
+    }
+    drop(tx);
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), (0..100).collect::<Vec<_>>());
+    }
+}
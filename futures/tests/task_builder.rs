@@ -0,0 +1,56 @@
+use futures::executor::block_on;
+use futures::future::{self, FutureObj, LocalFutureObj};
+use futures::task::{LocalSpawn, LocalSpawnExt, Spawn, SpawnError, SpawnExt, TaskMetadata};
+
+#[derive(Default)]
+struct RecordMetadata {
+    names: Vec<Option<String>>,
+    ids: Vec<Option<u32>>,
+}
+
+impl Spawn for RecordMetadata {
+    fn spawn_obj(&mut self, future: FutureObj<'static, ()>) -> Result<(), SpawnError> {
+        self.spawn_obj_with_metadata(future, TaskMetadata::new())
+    }
+
+    fn spawn_obj_with_metadata(
+        &mut self,
+        future: FutureObj<'static, ()>,
+        metadata: TaskMetadata,
+    ) -> Result<(), SpawnError> {
+        self.names.push(metadata.name().map(String::from));
+        self.ids.push(metadata.get::<u32>().cloned());
+        block_on(future);
+        Ok(())
+    }
+}
+
+// Relies on the default `spawn_local_obj_with_metadata`.
+#[derive(Default)]
+struct CountSpawns(usize);
+
+impl LocalSpawn for CountSpawns {
+    fn spawn_local_obj(&mut self, future: LocalFutureObj<'static, ()>) -> Result<(), SpawnError> {
+        self.0 += 1;
+        block_on(future);
+        Ok(())
+    }
+}
+
+#[test]
+fn metadata_reaches_spawner() {
+    let mut spawner = RecordMetadata::default();
+    spawner.build_task().name("conn-handler").metadata(1u32).metadata(7u32).spawn(future::ready(())).unwrap();
+    spawner.spawn(future::ready(())).unwrap();
+
+    assert_eq!(spawner.names, vec![Some("conn-handler".to_string()), None]);
+    assert_eq!(spawner.ids, vec![Some(7), None]);
+}
+
+#[test]
+fn spawner_without_metadata_support() {
+    let mut spawner = CountSpawns::default();
+    let handle = spawner.build_local_task().name("ignored").spawn_local_with_handle(future::ready(3)).unwrap();
+    assert_eq!(spawner.0, 1);
+    assert_eq!(block_on(handle), 3);
+}